@@ -3,6 +3,7 @@
 //! The ast module handles everything related building abstract SQL queries
 //! without going into database-level specifics.
 mod column;
+mod comment;
 mod common_table_expression;
 mod compare;
 mod conditions;
@@ -12,6 +13,7 @@ mod expression;
 mod function;
 mod grouping;
 mod insert;
+mod interval;
 mod join;
 mod ops;
 mod ordering;
@@ -19,20 +21,23 @@ mod over;
 mod query;
 mod row;
 mod select;
+mod sql_value;
 mod table;
 mod update;
 mod values;
 
 pub use column::{Column, TypeDataLength};
+pub use comment::Comment;
 pub use common_table_expression::CommonTableExpression;
-pub use compare::{Comparable, Compare, JsonCompare, JsonType};
-pub use conditions::ConditionTree;
+pub use compare::{Comparable, Compare, ComparisonOperator, JsonCompare, JsonType, Quantifier};
+pub use conditions::{exists, not_exists, ConditionTree};
 pub use conjunctive::Conjunctive;
 pub use delete::Delete;
 pub use expression::*;
 pub use function::*;
 pub use grouping::*;
 pub use insert::*;
+pub use interval::{interval, DateTimeField, Interval};
 pub use join::{Join, JoinData, Joinable};
 pub use ops::*;
 pub use ordering::{IntoOrderDefinition, Order, OrderDefinition, Orderable, Ordering};
@@ -41,6 +46,8 @@ pub use query::Query;
 pub use row::Row;
 pub use select::Select;
 pub use serde_json::{Map, Value};
+pub(crate) use sql_value::format_uuid;
+pub use sql_value::{SqlType, SqlValue};
 pub use table::*;
 pub use update::*;
 pub use values::Values;
@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+/// A comment rendered at the end of a statement. Holds free text, key-value
+/// tags in the [sqlcommenter](https://google.github.io/sqlcommenter/spec/)
+/// format, or both.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Comment<'a> {
+    pub(crate) text: Option<Cow<'a, str>>,
+    pub(crate) tags: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> Comment<'a> {
+    /// True if the comment has no text and no tags.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.tags.is_empty()
+    }
+
+    pub(crate) fn set_text(&mut self, text: impl Into<Cow<'a, str>>) {
+        self.text = Some(text.into());
+    }
+
+    pub(crate) fn push_tag(
+        &mut self,
+        key: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) {
+        self.tags.push((key.into(), value.into()));
+    }
+}
@@ -25,6 +25,32 @@ pub enum Compare<'a> {
     Like(Box<Expression<'a>>, Box<Expression<'a>>),
     /// `left NOT LIKE %..%`
     NotLike(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left ILIKE %..%`, case-insensitive `LIKE`
+    ILike(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left NOT ILIKE %..%`, case-insensitive `NOT LIKE`
+    NotILike(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left ~ pattern`, a POSIX regular expression match
+    RegexMatch(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left !~ pattern`
+    NotRegexMatch(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left ~* pattern`, a case-insensitive regular expression match
+    IRegexMatch(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left !~* pattern`
+    NotIRegexMatch(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left SIMILAR TO pattern`
+    SimilarTo(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left NOT SIMILAR TO pattern`
+    NotSimilarTo(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left LIKE 'value%'`, with the wildcards in the value escaped
+    StartsWith(Box<Expression<'a>>, Cow<'a, str>),
+    /// `left LIKE '%value'`, with the wildcards in the value escaped
+    EndsWith(Box<Expression<'a>>, Cow<'a, str>),
+    /// `left LIKE '%value%'`, with the wildcards in the value escaped
+    Contains(Box<Expression<'a>>, Cow<'a, str>),
+    /// `left IS DISTINCT FROM right`, a `<>` treating `NULL` as a value
+    DistinctFrom(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left IS NOT DISTINCT FROM right`, a `=` treating `NULL` as a value
+    NotDistinctFrom(Box<Expression<'a>>, Box<Expression<'a>>),
     /// `value IS NULL`
     Null(Box<Expression<'a>>),
     /// `value IS NOT NULL`
@@ -41,6 +67,14 @@ pub enum Compare<'a> {
         Box<Expression<'a>>,
         Box<Expression<'a>>,
     ),
+    /// `left <operator> ANY|SOME|ALL (right)`, comparing against every row of
+    /// a subquery or element of an array
+    Quantified(
+        Box<Expression<'a>>,
+        ComparisonOperator,
+        Quantifier,
+        Box<Expression<'a>>,
+    ),
     /// Raw comparator, allows to use an operator `left <raw> right` as is,
     /// without visitor transformation in between.
     Raw(Box<Expression<'a>>, Cow<'a, str>, Box<Expression<'a>>),
@@ -55,6 +89,60 @@ pub enum Compare<'a> {
     All(Box<Expression<'a>>),
 }
 
+/// A comparison operator, e.g. for a quantified comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    /// `=`
+    Equals,
+    /// `<>`
+    NotEquals,
+    /// `<`
+    LessThan,
+    /// `<=`
+    LessThanOrEquals,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEquals,
+}
+
+impl ComparisonOperator {
+    /// Compares the two expressions with the operator.
+    pub(crate) fn compare<'a>(self, left: Expression<'a>, right: Expression<'a>) -> Compare<'a> {
+        match self {
+            ComparisonOperator::Equals => left.equals(right),
+            ComparisonOperator::NotEquals => left.not_equals(right),
+            ComparisonOperator::LessThan => left.less_than(right),
+            ComparisonOperator::LessThanOrEquals => left.less_than_or_equals(right),
+            ComparisonOperator::GreaterThan => left.greater_than(right),
+            ComparisonOperator::GreaterThanOrEquals => left.greater_than_or_equals(right),
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ComparisonOperator::Equals => "=",
+            ComparisonOperator::NotEquals => "<>",
+            ComparisonOperator::LessThan => "<",
+            ComparisonOperator::LessThanOrEquals => "<=",
+            ComparisonOperator::GreaterThan => ">",
+            ComparisonOperator::GreaterThanOrEquals => ">=",
+        }
+    }
+}
+
+/// Whether a quantified comparison must hold for any or for all rows of the
+/// subquery or elements of the array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    /// `ANY`
+    Any,
+    /// `SOME`, the same as `ANY`
+    Some,
+    /// `ALL`
+    All,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum JsonCompare<'a> {
     ArrayOverlaps(Box<Expression<'a>>, Box<Expression<'a>>),
@@ -149,6 +237,107 @@ pub trait Comparable<'a> {
     where
         T: Into<Expression<'a>>;
 
+    /// Tests if the left side includes the right side string, ignoring case.
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side does not include the right side string, ignoring case.
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side matches the regular expression. SQLite only
+    /// supports `REGEXP` if the application defines a `regexp` function.
+    fn regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side does not match the regular expression.
+    fn not_regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side matches the regular expression, ignoring case.
+    fn iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side does not match the regular expression, ignoring
+    /// case.
+    fn not_iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side matches the SQL standard `SIMILAR TO` pattern.
+    /// MySQL and SQLite do not support `SIMILAR TO`, and rendering it for
+    /// them panics.
+    fn similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side does not match the `SIMILAR TO` pattern.
+    fn not_similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side starts with the string. Unlike with `like`,
+    /// wildcards in the string match literally.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that(Column::from("discount").has_prefix("50%"));
+    ///
+    /// let (sql, params) = Postgres::build(query);
+    ///
+    /// assert_eq!(r#"SELECT "users".* FROM "users" WHERE "discount"::text LIKE $1"#, sql);
+    /// assert_eq!(vec![Value::from(r"50\%%")], params);
+    /// # }
+    /// ```
+    fn has_prefix<T>(self, prefix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if the left side ends with the string. Wildcards in the string
+    /// match literally.
+    fn has_suffix<T>(self, suffix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if the left side includes the string. Wildcards in the string
+    /// match literally.
+    fn has_substring<T>(self, substring: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>;
+
+    /// Tests if both sides are not the same value, treating two `NULL`s as the
+    /// same and `NULL` as different from any other value.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if both sides are the same value, treating two `NULL`s as the same
+    /// and `NULL` as different from any other value.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("parent_id".is_not_distinct_from(Value::Null));
+    ///
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(r#"SELECT "users".* FROM "users" WHERE "parent_id" IS NOT DISTINCT FROM $1"#, sql);
+    /// # }
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
     /// Tests if the left side is `NULL`.
     #[allow(clippy::wrong_self_convention)]
     fn is_null(self) -> Compare<'a>;
@@ -237,6 +426,35 @@ pub trait Comparable<'a> {
     #[cfg(feature = "postgresql")]
     fn all(self) -> Compare<'a>;
 
+    /// Compares the left side with every row of a subquery or element of an
+    /// array, holding for any or for all of them.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+    /// # fn main() {
+    /// let mut scores = Select::from_table("scores");
+    /// scores.column("value");
+    ///
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("score".quantified(ComparisonOperator::GreaterThan, Quantifier::All, scores));
+    ///
+    /// let (sql, _) = Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" WHERE "score" > ALL (SELECT "value" FROM "scores")"#,
+    ///     sql
+    /// );
+    /// # }
+    /// ```
+    fn quantified<T>(
+        self,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: T,
+    ) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
     /// Compares two expressions with a custom operator.
     fn compare_raw<T, V>(self, raw_comparator: T, right: V) -> Compare<'a>
     where
@@ -339,6 +557,125 @@ where
         val.not_like(pattern)
     }
 
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.ilike(pattern)
+    }
+
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.not_ilike(pattern)
+    }
+
+    fn regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.regex_match(pattern)
+    }
+
+    fn not_regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.not_regex_match(pattern)
+    }
+
+    fn iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.iregex_match(pattern)
+    }
+
+    fn not_iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.not_iregex_match(pattern)
+    }
+
+    fn similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.similar_to(pattern)
+    }
+
+    fn not_similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.not_similar_to(pattern)
+    }
+
+    fn has_prefix<T>(self, prefix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.has_prefix(prefix)
+    }
+
+    fn has_suffix<T>(self, suffix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.has_suffix(suffix)
+    }
+
+    fn has_substring<T>(self, substring: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.has_substring(substring)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.is_distinct_from(comparison)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.is_not_distinct_from(comparison)
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn is_null(self) -> Compare<'a> {
         let col: Column<'a> = self.into();
@@ -385,6 +722,20 @@ where
         left.compare_raw(raw_comparator.into(), right)
     }
 
+    fn quantified<T>(
+        self,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: T,
+    ) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.quantified(operator, quantifier, right)
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql"))]
     fn array_overlaps<T>(self, item: T) -> Compare<'a>
     where
@@ -1,7 +1,5 @@
 use crate::ast::{Expression, ExpressionKind, Select};
 
-use super::Table;
-
 /// Tree structures and leaves for condition building.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub enum ConditionTree<'a> {
@@ -18,8 +16,10 @@ pub enum ConditionTree<'a> {
     NoCondition,
     /// A leaf that cancels the condition, `1=0`
     NegativeCondition,
-    /// Exists condition
-    Exists(Box<Table<'a>>),
+    /// `EXISTS (SELECT ..)`
+    Exists(Box<Select<'a>>),
+    /// `NOT EXISTS (SELECT ..)`
+    NotExists(Box<Select<'a>>),
 }
 
 impl<'a> ConditionTree<'a> {
@@ -69,14 +69,22 @@ impl<'a> ConditionTree<'a> {
         ConditionTree::Single(Box::new(left.into()))
     }
 
-    /// True if a nested table has any values.
+    /// True if the subquery returns any rows.
     pub fn exists<E>(select: E) -> ConditionTree<'a>
     where
-        E: Into<Table<'a>>,
+        E: Into<Select<'a>>,
     {
         ConditionTree::Exists(Box::new(select.into()))
     }
 
+    /// True if the subquery returns no rows.
+    pub fn not_exists<E>(select: E) -> ConditionTree<'a>
+    where
+        E: Into<Select<'a>>,
+    {
+        ConditionTree::NotExists(Box::new(select.into()))
+    }
+
     /// Inverts the entire condition tree if condition is met.
     pub fn invert_if(self, invert: bool) -> ConditionTree<'a> {
         if invert {
@@ -106,3 +114,32 @@ impl<'a> From<Select<'a>> for ConditionTree<'a> {
         ConditionTree::single(exp)
     }
 }
+
+/// True if the subquery returns any rows, e.g. to filter by related rows.
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+/// # fn main() {
+/// let mut posts = Select::from_table("posts");
+/// posts.value(1);
+/// posts.so_that(Column::from(("posts", "user_id")).equals(Column::from(("users", "id"))));
+///
+/// let mut query = Select::from_table("users");
+/// query.so_that(exists(posts));
+///
+/// let (sql, _) = Postgres::build(query);
+///
+/// assert_eq!(
+///     r#"SELECT "users".* FROM "users" WHERE EXISTS (SELECT $1 FROM "posts" WHERE "posts"."user_id" = "users"."id")"#,
+///     sql
+/// );
+/// # }
+/// ```
+pub fn exists<'a>(select: impl Into<Select<'a>>) -> ConditionTree<'a> {
+    ConditionTree::exists(select)
+}
+
+/// True if the subquery returns no rows.
+pub fn not_exists<'a>(select: impl Into<Select<'a>>) -> ConditionTree<'a> {
+    ConditionTree::not_exists(select)
+}
@@ -1,4 +1,5 @@
-use crate::ast::{ConditionTree, Query, Table};
+use crate::ast::{Comment, ConditionTree, Query, Table};
+use std::borrow::Cow;
 
 use super::Expression;
 
@@ -8,6 +9,7 @@ pub struct Delete<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) returning: Option<Vec<Expression<'a>>>,
+    pub(crate) comment: Comment<'a>,
 }
 
 impl<'a> From<Delete<'a>> for Query<'a> {
@@ -36,6 +38,7 @@ impl<'a> Delete<'a> {
             table: table.into(),
             conditions: None,
             returning: None,
+            comment: Comment::default(),
         }
     }
 
@@ -82,4 +85,31 @@ impl<'a> Delete<'a> {
     {
         self.returning = Some(returning.into_iter().map(Into::into).collect());
     }
+
+    /// Adds a comment to the end of the statement.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, self}};
+    /// # fn main() {
+    /// let mut query = Delete::from_table("users");
+    /// query.comment("*/ DROP TABLE users; /*");
+    ///
+    /// let (sql, _) = renderer::Postgres::build(query);
+    ///
+    /// assert_eq!(r#"DELETE FROM "users" /* * / DROP TABLE users; / * */"#, sql);
+    /// # }
+    /// ```
+    pub fn comment<C: Into<Cow<'a, str>>>(&mut self, comment: C) {
+        self.comment.set_text(comment);
+    }
+
+    /// Adds a key-value tag to the statement comment, rendered in the
+    /// sqlcommenter format.
+    pub fn tag<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.comment.push_tag(key, value);
+    }
 }
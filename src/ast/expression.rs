@@ -1,8 +1,8 @@
 use serde_json::Value;
 
 use crate::ast::{
-    Aliasable, Column, Comparable, Compare, ConditionTree, Function, Row, Select, SqlOp, Table,
-    Values,
+    Aliasable, Column, Comparable, Compare, ComparisonOperator, ConditionTree, Function, Interval,
+    Quantifier, Row, Select, SqlOp, SqlValue, Table, Values,
 };
 
 #[cfg(any(feature = "postgresql", feature = "mysql"))]
@@ -28,9 +28,13 @@ impl<'a> Expression<'a> {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExpressionKind<'a> {
     /// Anything that we must parameterize before querying
-    Parameterized(Value),
+    Parameterized(SqlValue),
+    /// A named parameter without a value, bound after rendering
+    Placeholder(Cow<'a, str>),
     /// Will be rendered as-is to the SQL statement. Carefully escape, if needed.
     Raw(&'a str),
+    /// Rendered as-is, with every `?` replaced by the next expression.
+    RawWithParams(&'a str, Vec<Expression<'a>>),
     /// A database column
     Column(Box<Column<'a>>),
     /// A database column
@@ -55,6 +59,8 @@ pub enum ExpressionKind<'a> {
     Values(Values<'a>),
     /// DEFAULT keyword, e.g. for `INSERT INTO ... VALUES (..., DEFAULT, ...)`
     Default,
+    /// An interval literal, e.g. seven days
+    Interval(Interval),
 }
 
 /// A quick alias to create a raw value expression.
@@ -65,6 +71,83 @@ pub fn raw(value: &str) -> Expression<'_> {
     }
 }
 
+/// A raw SQL fragment with a `?` for every expression, replaced by the
+/// rendered expression. Parameters get numbered by the renderer like in any
+/// other part of the query. Write `??` for a literal question mark.
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+/// # fn main() {
+/// let rank = raw_with_params(
+///     "ts_rank(?, to_tsquery(?))",
+///     [Expression::from(Column::from("document")), Expression::from("cat")],
+/// );
+///
+/// let mut query = Select::from_table("posts");
+/// query.so_that("id".equals(1));
+/// query.value(rank.alias("rank"));
+///
+/// let (sql, params) = Postgres::build(query);
+///
+/// assert_eq!(
+///     r#"SELECT ts_rank("document", to_tsquery($1)) AS "rank" FROM "posts" WHERE "id" = $2"#,
+///     sql
+/// );
+/// assert_eq!(vec![Value::from("cat"), Value::from(1)], params);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// If the number of `?` in the fragment differs from the number of
+/// expressions.
+pub fn raw_with_params<'a, I, E>(value: &'a str, params: I) -> Expression<'a>
+where
+    I: IntoIterator<Item = E>,
+    E: Into<Expression<'a>>,
+{
+    let params: Vec<_> = params.into_iter().map(Into::into).collect();
+    let placeholders = value.replace("??", "").matches('?').count();
+
+    assert_eq!(
+        placeholders,
+        params.len(),
+        "The raw fragment has {} placeholders for {} expressions.",
+        placeholders,
+        params.len()
+    );
+
+    Expression {
+        kind: ExpressionKind::RawWithParams(value, params),
+        alias: None,
+    }
+}
+
+/// A named placeholder for a value bound after the query is rendered. Every
+/// use of the same name refers to the same value.
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+/// # fn main() {
+/// let mut query = Select::from_table("users");
+/// query.so_that("id".equals(placeholder("id")).or("parent_id".equals(placeholder("id"))));
+///
+/// let template = Postgres::new().render_template(query);
+///
+/// assert_eq!(
+///     r#"SELECT "users".* FROM "users" WHERE ("id" = $1 OR "parent_id" = $1)"#,
+///     template.sql
+/// );
+/// assert_eq!(Some(&0), template.placeholders.get("id"));
+/// # }
+/// ```
+pub fn placeholder<'a>(name: impl Into<Cow<'a, str>>) -> Expression<'a> {
+    Expression {
+        kind: ExpressionKind::Placeholder(name.into()),
+        alias: None,
+    }
+}
+
 /// A quick alias to create an asterisk to a table.
 pub fn asterisk() -> Expression<'static> {
     Expression {
@@ -114,7 +197,7 @@ where
 {
     fn from(p: T) -> Self {
         Expression {
-            kind: ExpressionKind::Parameterized(p.into()),
+            kind: ExpressionKind::Parameterized(SqlValue::from(p.into())),
             alias: None,
         }
     }
@@ -227,6 +310,99 @@ impl<'a> Comparable<'a> for Expression<'a> {
         Compare::NotLike(Box::new(self), Box::new(pattern.into()))
     }
 
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::ILike(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::NotILike(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::RegexMatch(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn not_regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::NotRegexMatch(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::IRegexMatch(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn not_iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::NotIRegexMatch(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::SimilarTo(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn not_similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::NotSimilarTo(Box::new(self), Box::new(pattern.into()))
+    }
+
+    fn has_prefix<T>(self, prefix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::StartsWith(Box::new(self), prefix.into())
+    }
+
+    fn has_suffix<T>(self, suffix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::EndsWith(Box::new(self), suffix.into())
+    }
+
+    fn has_substring<T>(self, substring: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Compare::Contains(Box::new(self), substring.into())
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::DistinctFrom(Box::new(self), Box::new(comparison.into()))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::NotDistinctFrom(Box::new(self), Box::new(comparison.into()))
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn is_null(self) -> Compare<'a> {
         Compare::Null(Box::new(self))
@@ -273,6 +449,18 @@ impl<'a> Comparable<'a> for Expression<'a> {
         )
     }
 
+    fn quantified<T>(
+        self,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: T,
+    ) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::Quantified(Box::new(self), operator, quantifier, Box::new(right.into()))
+    }
+
     #[cfg(all(feature = "postgresql", feature = "mysql"))]
     fn array_contains<T>(self, item: T) -> Compare<'a>
     where
@@ -1,10 +1,19 @@
+mod abs;
 mod aggregate_to_string;
+mod at_time_zone;
 mod average;
+mod ceil;
 mod coalesce;
 mod concat;
 mod count;
+mod current_timestamp;
+mod date_trunc;
 #[cfg(feature = "postgresql")]
 mod encode;
+mod extract;
+mod floor;
+mod function_call;
+mod greatest_least;
 #[cfg(feature = "postgresql")]
 mod json_agg;
 #[cfg(feature = "postgresql")]
@@ -15,24 +24,46 @@ mod json_extract;
 mod json_extract_array;
 #[cfg(any(feature = "postgresql", feature = "mysql"))]
 mod json_unquote;
+mod left_right;
+mod length;
 mod lower;
 mod maximum;
 mod minimum;
+mod modulo;
+mod nullif;
+mod pad;
+mod position;
+mod power;
+mod replace;
+mod round;
 mod row_number;
 #[cfg(feature = "postgresql")]
 mod row_to_json;
+mod split_part;
+mod sqrt;
+mod substring;
 mod sum;
 #[cfg(feature = "postgresql")]
 mod to_jsonb;
+mod trim;
 mod upper;
 
+pub use abs::*;
 pub use aggregate_to_string::*;
+pub use at_time_zone::*;
 pub use average::*;
+pub use ceil::*;
 pub use coalesce::*;
 pub use concat::*;
 pub use count::*;
+pub use current_timestamp::*;
+pub use date_trunc::*;
 #[cfg(feature = "postgresql")]
 pub use encode::*;
+pub use extract::*;
+pub use floor::*;
+pub use function_call::*;
+pub use greatest_least::*;
 #[cfg(feature = "postgresql")]
 pub use json_agg::*;
 #[cfg(feature = "postgresql")]
@@ -43,15 +74,28 @@ pub use json_extract::*;
 pub(crate) use json_extract_array::*;
 #[cfg(any(feature = "postgresql", feature = "mysql"))]
 pub use json_unquote::*;
+pub use left_right::*;
+pub use length::*;
 pub use lower::*;
 pub use maximum::*;
 pub use minimum::*;
+pub use modulo::*;
+pub use nullif::*;
+pub use pad::*;
+pub use position::*;
+pub use power::*;
+pub use replace::*;
+pub use round::*;
 pub use row_number::*;
 #[cfg(feature = "postgresql")]
 pub use row_to_json::*;
+pub use split_part::*;
+pub use sqrt::*;
+pub use substring::*;
 pub use sum::*;
 #[cfg(feature = "postgresql")]
 pub use to_jsonb::*;
+pub use trim::*;
 pub use upper::*;
 
 use super::Aliasable;
@@ -70,7 +114,7 @@ impl<'a> Function<'a> {
             #[cfg(feature = "postgresql")]
             FunctionType::RowToJson(_) => true,
             #[cfg(any(feature = "postgresql", feature = "mysql"))]
-            FunctionType::JsonExtract(_) => true,
+            FunctionType::JsonExtract(ref extract) => !extract.extract_as_string,
             #[cfg(any(feature = "postgresql", feature = "mysql"))]
             FunctionType::JsonExtractLastArrayElem(_) => true,
             #[cfg(any(feature = "postgresql", feature = "mysql"))]
@@ -95,6 +139,29 @@ pub(crate) enum FunctionType<'a> {
     Maximum(Maximum<'a>),
     Coalesce(Coalesce<'a>),
     Concat(Concat<'a>),
+    FunctionCall(FunctionCall<'a>),
+    Substring(Substring<'a>),
+    Trim(Trim<'a>),
+    Length(Length<'a>),
+    Replace(Replace<'a>),
+    Position(Position<'a>),
+    Left(Left<'a>),
+    Right(Right<'a>),
+    Pad(Pad<'a>),
+    SplitPart(SplitPart<'a>),
+    CurrentTimestamp(CurrentTimestamp),
+    DateTrunc(DateTrunc<'a>),
+    Extract(Extract<'a>),
+    AtTimeZone(AtTimeZone<'a>),
+    Abs(Abs<'a>),
+    Round(Round<'a>),
+    Ceil(Ceil<'a>),
+    Floor(Floor<'a>),
+    Power(Power<'a>),
+    Sqrt(Sqrt<'a>),
+    Modulo(Modulo<'a>),
+    GreatestLeast(GreatestLeast<'a>),
+    NullIf(NullIf<'a>),
     #[cfg(any(feature = "postgresql", feature = "mysql"))]
     JsonExtract(JsonExtract<'a>),
     #[cfg(any(feature = "postgresql", feature = "mysql"))]
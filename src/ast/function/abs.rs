@@ -0,0 +1,29 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `ABS` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Abs<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The absolute value of the number.
+pub fn abs<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Abs {
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Abs<'a>> for Function<'a> {
+    fn from(value: Abs<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Abs(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `AT TIME ZONE` operator in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtTimeZone<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) time_zone: Box<Expression<'a>>,
+}
+
+/// Converts the timestamp to the local time in the given time zone, such as
+/// `Europe/Helsinki`. SQLite has no time zones, and rendering it for SQLite
+/// panics.
+pub fn at_time_zone<'a, E, Z>(expression: E, time_zone: Z) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    Z: Into<Expression<'a>>,
+{
+    let fun = AtTimeZone {
+        expression: Box::new(expression.into()),
+        time_zone: Box::new(time_zone.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<AtTimeZone<'a>> for Function<'a> {
+    fn from(value: AtTimeZone<'a>) -> Self {
+        Self {
+            typ_: FunctionType::AtTimeZone(value),
+            alias: None,
+        }
+    }
+}
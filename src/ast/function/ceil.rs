@@ -0,0 +1,29 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `CEIL` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ceil<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The smallest integer not less than the number.
+pub fn ceil<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Ceil {
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Ceil<'a>> for Function<'a> {
+    fn from(value: Ceil<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Ceil(value),
+            alias: None,
+        }
+    }
+}
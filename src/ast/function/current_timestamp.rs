@@ -0,0 +1,21 @@
+use super::Function;
+use crate::ast::FunctionType;
+
+/// A represention of the `CURRENT_TIMESTAMP` function in the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentTimestamp;
+
+/// The timestamp at the start of the current transaction, rendered as
+/// `CURRENT_TIMESTAMP`.
+pub fn now<'a>() -> Function<'a> {
+    CurrentTimestamp.into()
+}
+
+impl<'a> From<CurrentTimestamp> for Function<'a> {
+    fn from(value: CurrentTimestamp) -> Self {
+        Self {
+            typ_: FunctionType::CurrentTimestamp(value),
+            alias: None,
+        }
+    }
+}
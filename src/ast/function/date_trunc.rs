@@ -0,0 +1,32 @@
+use super::Function;
+use crate::ast::{DateTimeField, Expression, FunctionType};
+
+/// A represention of the `DATE_TRUNC` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTrunc<'a> {
+    pub(crate) field: DateTimeField,
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// Truncates the timestamp to the start of the given field, e.g. to
+/// midnight for `DateTimeField::Day`. Weeks start on Monday.
+pub fn date_trunc<'a, E>(field: DateTimeField, expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = DateTrunc {
+        field,
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<DateTrunc<'a>> for Function<'a> {
+    fn from(value: DateTrunc<'a>) -> Self {
+        Self {
+            typ_: FunctionType::DateTrunc(value),
+            alias: None,
+        }
+    }
+}
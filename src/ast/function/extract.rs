@@ -0,0 +1,31 @@
+use super::Function;
+use crate::ast::{DateTimeField, Expression, FunctionType};
+
+/// A represention of the `EXTRACT` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extract<'a> {
+    pub(crate) field: DateTimeField,
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The given field of the timestamp as a number, e.g. the month.
+pub fn extract<'a, E>(field: DateTimeField, expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Extract {
+        field,
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Extract<'a>> for Function<'a> {
+    fn from(value: Extract<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Extract(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `FLOOR` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Floor<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The largest integer not greater than the number.
+pub fn floor<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Floor {
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Floor<'a>> for Function<'a> {
+    fn from(value: Floor<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Floor(value),
+            alias: None,
+        }
+    }
+}
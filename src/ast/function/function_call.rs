@@ -0,0 +1,93 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A call to a function by its name, such as a user-defined function or a
+/// function from an extension.
+pub struct FunctionCall<'a> {
+    pub(crate) schema: Option<Cow<'a, str>>,
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) arguments: Vec<Expression<'a>>,
+    pub(crate) distinct: bool,
+}
+
+/// Calls the function with the given arguments. A name with characters other
+/// than ASCII letters, digits and underscores, or a reserved keyword, is
+/// quoted, so it can never break out of the call. Other names are written
+/// as-is and resolved case-insensitively.
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+/// # fn main() {
+/// let similarity = function_call("similarity", [Expression::from(Column::from("name")), Expression::from("Musti")])
+///     .schema("extensions");
+///
+/// let mut query = Select::from_table("cats");
+/// query.value(Function::from(similarity).alias("score"));
+///
+/// let (sql, params) = Postgres::build(query);
+///
+/// assert_eq!(
+///     r#"SELECT extensions.similarity("name", $1) AS "score" FROM "cats""#,
+///     sql
+/// );
+/// assert_eq!(vec![Value::from("Musti")], params);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// If the name is empty.
+pub fn function_call<'a, N, T, V>(name: N, arguments: V) -> FunctionCall<'a>
+where
+    N: Into<Cow<'a, str>>,
+    T: Into<Expression<'a>>,
+    V: IntoIterator<Item = T>,
+{
+    let name = name.into();
+    assert!(!name.is_empty(), "The function name cannot be empty.");
+
+    FunctionCall {
+        schema: None,
+        name,
+        arguments: arguments.into_iter().map(Into::into).collect(),
+        distinct: false,
+    }
+}
+
+impl<'a> FunctionCall<'a> {
+    /// Qualifies the function with the schema it is defined in.
+    ///
+    /// # Panics
+    ///
+    /// If the schema name is empty.
+    pub fn schema(mut self, schema: impl Into<Cow<'a, str>>) -> Self {
+        let schema = schema.into();
+        assert!(!schema.is_empty(), "The schema name cannot be empty.");
+
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Adds `DISTINCT` before the arguments of an aggregate function.
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+}
+
+impl<'a> From<FunctionCall<'a>> for Function<'a> {
+    fn from(value: FunctionCall<'a>) -> Self {
+        Self {
+            typ_: FunctionType::FunctionCall(value),
+            alias: None,
+        }
+    }
+}
+
+impl<'a> From<FunctionCall<'a>> for Expression<'a> {
+    fn from(value: FunctionCall<'a>) -> Self {
+        Function::from(value).into()
+    }
+}
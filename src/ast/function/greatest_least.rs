@@ -0,0 +1,52 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `GREATEST` and `LEAST` functions in the database.
+/// SQLite uses the scalar `MAX` and `MIN` functions instead. On MySQL and
+/// SQLite the result is `NULL` if any value is `NULL`, while PostgreSQL
+/// ignores `NULL` values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreatestLeast<'a> {
+    pub(crate) exprs: Vec<Expression<'a>>,
+    pub(crate) greatest: bool,
+}
+
+/// The largest of the given values.
+pub fn greatest<'a, T, V>(exprs: V) -> Function<'a>
+where
+    T: Into<Expression<'a>>,
+    V: Into<Vec<T>>,
+{
+    greatest_least(exprs, true)
+}
+
+/// The smallest of the given values.
+pub fn least<'a, T, V>(exprs: V) -> Function<'a>
+where
+    T: Into<Expression<'a>>,
+    V: Into<Vec<T>>,
+{
+    greatest_least(exprs, false)
+}
+
+fn greatest_least<'a, T, V>(exprs: V, greatest: bool) -> Function<'a>
+where
+    T: Into<Expression<'a>>,
+    V: Into<Vec<T>>,
+{
+    let fun = GreatestLeast {
+        exprs: exprs.into().into_iter().map(|e| e.into()).collect(),
+        greatest,
+    };
+
+    fun.into()
+}
+
+impl<'a> From<GreatestLeast<'a>> for Function<'a> {
+    fn from(value: GreatestLeast<'a>) -> Self {
+        Self {
+            typ_: FunctionType::GreatestLeast(value),
+            alias: None,
+        }
+    }
+}
@@ -2,11 +2,15 @@ use super::{Function, FunctionType};
 use crate::ast::Expression;
 use std::borrow::Cow;
 
+/// A representation of the `json_build_object` function in PostgreSQL.
 #[derive(Debug, Clone, PartialEq)]
 pub struct JsonBuildObject<'a> {
     pub(crate) values: Vec<(Cow<'a, str>, Expression<'a>)>,
 }
 
+/// Builds a JSON object from the given key-value pairs. The keys are rendered as
+/// escaped string literals. Objects with more than 50 keys are rendered as
+/// several `jsonb_build_object` calls merged with `||`, and are of type `jsonb`.
 pub fn json_build_object<'a, S, E>(values: impl IntoIterator<Item = (S, E)>) -> Function<'a>
 where
     S: Into<Cow<'a, str>>,
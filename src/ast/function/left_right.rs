@@ -0,0 +1,62 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `LEFT` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Left<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) count: Box<Expression<'a>>,
+}
+
+/// A represention of the `RIGHT` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Right<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) count: Box<Expression<'a>>,
+}
+
+/// The first `count` characters of the string.
+pub fn left<'a, E, C>(expression: E, count: C) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    C: Into<Expression<'a>>,
+{
+    let fun = Left {
+        expression: Box::new(expression.into()),
+        count: Box::new(count.into()),
+    };
+
+    fun.into()
+}
+
+/// The last `count` characters of the string.
+pub fn right<'a, E, C>(expression: E, count: C) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    C: Into<Expression<'a>>,
+{
+    let fun = Right {
+        expression: Box::new(expression.into()),
+        count: Box::new(count.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Left<'a>> for Function<'a> {
+    fn from(value: Left<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Left(value),
+            alias: None,
+        }
+    }
+}
+
+impl<'a> From<Right<'a>> for Function<'a> {
+    fn from(value: Right<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Right(value),
+            alias: None,
+        }
+    }
+}
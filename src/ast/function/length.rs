@@ -0,0 +1,29 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `CHAR_LENGTH` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Length<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The number of characters in the string.
+pub fn length<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Length {
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Length<'a>> for Function<'a> {
+    fn from(value: Length<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Length(value),
+            alias: None,
+        }
+    }
+}
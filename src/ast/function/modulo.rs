@@ -0,0 +1,32 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `MOD` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Modulo<'a> {
+    pub(crate) dividend: Box<Expression<'a>>,
+    pub(crate) divisor: Box<Expression<'a>>,
+}
+
+/// The remainder of dividing the dividend by the divisor.
+pub fn modulo<'a, L, R>(dividend: L, divisor: R) -> Function<'a>
+where
+    L: Into<Expression<'a>>,
+    R: Into<Expression<'a>>,
+{
+    let fun = Modulo {
+        dividend: Box::new(dividend.into()),
+        divisor: Box::new(divisor.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Modulo<'a>> for Function<'a> {
+    fn from(value: Modulo<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Modulo(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `NULLIF` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NullIf<'a> {
+    pub(crate) left: Box<Expression<'a>>,
+    pub(crate) right: Box<Expression<'a>>,
+}
+
+/// `NULL` if both sides are equal, otherwise the left side.
+pub fn nullif<'a, L, R>(left: L, right: R) -> Function<'a>
+where
+    L: Into<Expression<'a>>,
+    R: Into<Expression<'a>>,
+{
+    let fun = NullIf {
+        left: Box::new(left.into()),
+        right: Box::new(right.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<NullIf<'a>> for Function<'a> {
+    fn from(value: NullIf<'a>) -> Self {
+        Self {
+            typ_: FunctionType::NullIf(value),
+            alias: None,
+        }
+    }
+}
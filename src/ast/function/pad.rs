@@ -0,0 +1,59 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `LPAD` and `RPAD` functions in the database. SQLite
+/// has no padding functions, and rendering them for SQLite panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pad<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) length: Box<Expression<'a>>,
+    pub(crate) fill: Box<Expression<'a>>,
+    pub(crate) left: bool,
+}
+
+/// Pads the start of the string with `fill` up to `length` characters,
+/// truncating longer strings.
+pub fn lpad<'a, E, L, F>(expression: E, length: L, fill: F) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+    F: Into<Expression<'a>>,
+{
+    pad(expression.into(), length.into(), fill.into(), true)
+}
+
+/// Pads the end of the string with `fill` up to `length` characters,
+/// truncating longer strings.
+pub fn rpad<'a, E, L, F>(expression: E, length: L, fill: F) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+    F: Into<Expression<'a>>,
+{
+    pad(expression.into(), length.into(), fill.into(), false)
+}
+
+fn pad<'a>(
+    expression: Expression<'a>,
+    length: Expression<'a>,
+    fill: Expression<'a>,
+    left: bool,
+) -> Function<'a> {
+    let fun = Pad {
+        expression: Box::new(expression),
+        length: Box::new(length),
+        fill: Box::new(fill),
+        left,
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Pad<'a>> for Function<'a> {
+    fn from(value: Pad<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Pad(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `POSITION` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position<'a> {
+    pub(crate) substring: Box<Expression<'a>>,
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The 1-based position of the first occurrence of `substring` in the
+/// string, or zero if it does not occur.
+pub fn position<'a, S, E>(substring: S, expression: E) -> Function<'a>
+where
+    S: Into<Expression<'a>>,
+    E: Into<Expression<'a>>,
+{
+    let fun = Position {
+        substring: Box::new(substring.into()),
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Position<'a>> for Function<'a> {
+    fn from(value: Position<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Position(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `POWER` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Power<'a> {
+    pub(crate) base: Box<Expression<'a>>,
+    pub(crate) exponent: Box<Expression<'a>>,
+}
+
+/// The base raised to the power of the exponent.
+pub fn power<'a, L, R>(base: L, exponent: R) -> Function<'a>
+where
+    L: Into<Expression<'a>>,
+    R: Into<Expression<'a>>,
+{
+    let fun = Power {
+        base: Box::new(base.into()),
+        exponent: Box::new(exponent.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Power<'a>> for Function<'a> {
+    fn from(value: Power<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Power(value),
+            alias: None,
+        }
+    }
+}
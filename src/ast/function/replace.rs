@@ -0,0 +1,35 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `REPLACE` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replace<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) from: Box<Expression<'a>>,
+    pub(crate) to: Box<Expression<'a>>,
+}
+
+/// Replaces every occurrence of `from` in the string with `to`.
+pub fn replace<'a, E, F, T>(expression: E, from: F, to: T) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    F: Into<Expression<'a>>,
+    T: Into<Expression<'a>>,
+{
+    let fun = Replace {
+        expression: Box::new(expression.into()),
+        from: Box::new(from.into()),
+        to: Box::new(to.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Replace<'a>> for Function<'a> {
+    fn from(value: Replace<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Replace(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `ROUND` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Round<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) digits: Option<Box<Expression<'a>>>,
+}
+
+/// Rounds the number to the given number of decimal digits, or to the
+/// nearest integer if `None`.
+pub fn round<'a, E>(expression: E, digits: Option<Expression<'a>>) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Round {
+        expression: Box::new(expression.into()),
+        digits: digits.map(Box::new),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Round<'a>> for Function<'a> {
+    fn from(value: Round<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Round(value),
+            alias: None,
+        }
+    }
+}
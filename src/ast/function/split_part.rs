@@ -0,0 +1,37 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `SPLIT_PART` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPart<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) delimiter: Box<Expression<'a>>,
+    pub(crate) index: Box<Expression<'a>>,
+}
+
+/// Splits the string at every `delimiter` and returns the part at the 1-based
+/// `index`. SQLite has no function to split strings, and rendering it for
+/// SQLite panics.
+pub fn split_part<'a, E, D, I>(expression: E, delimiter: D, index: I) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    D: Into<Expression<'a>>,
+    I: Into<Expression<'a>>,
+{
+    let fun = SplitPart {
+        expression: Box::new(expression.into()),
+        delimiter: Box::new(delimiter.into()),
+        index: Box::new(index.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<SplitPart<'a>> for Function<'a> {
+    fn from(value: SplitPart<'a>) -> Self {
+        Self {
+            typ_: FunctionType::SplitPart(value),
+            alias: None,
+        }
+    }
+}
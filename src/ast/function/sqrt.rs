@@ -0,0 +1,29 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `SQRT` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sqrt<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+}
+
+/// The square root of the number.
+pub fn sqrt<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Sqrt {
+        expression: Box::new(expression.into()),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Sqrt<'a>> for Function<'a> {
+    fn from(value: Sqrt<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Sqrt(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// A represention of the `SUBSTRING` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Substring<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) start: Box<Expression<'a>>,
+    pub(crate) length: Option<Box<Expression<'a>>>,
+}
+
+/// Extracts the part of the string starting at the 1-based position `start`,
+/// up to `length` characters or until the end of the string.
+pub fn substring<'a, E, S>(expression: E, start: S, length: Option<Expression<'a>>) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    S: Into<Expression<'a>>,
+{
+    let fun = Substring {
+        expression: Box::new(expression.into()),
+        start: Box::new(start.into()),
+        length: length.map(Box::new),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Substring<'a>> for Function<'a> {
+    fn from(value: Substring<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Substring(value),
+            alias: None,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::Function;
+use crate::ast::{Expression, FunctionType};
+
+/// The side of the string to trim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimSide {
+    /// `LEADING`, the start of the string
+    Leading,
+    /// `TRAILING`, the end of the string
+    Trailing,
+    /// `BOTH`, both ends of the string
+    Both,
+}
+
+/// A represention of the `TRIM` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trim<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) side: TrimSide,
+    pub(crate) characters: Option<Box<Expression<'a>>>,
+}
+
+/// Removes whitespace from both ends of the string.
+pub fn trim<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    trim_characters(expression, TrimSide::Both, None)
+}
+
+/// Removes whitespace from the start of the string.
+pub fn ltrim<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    trim_characters(expression, TrimSide::Leading, None)
+}
+
+/// Removes whitespace from the end of the string.
+pub fn rtrim<'a, E>(expression: E) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    trim_characters(expression, TrimSide::Trailing, None)
+}
+
+/// Removes the given characters, or whitespace if `None`, from the given
+/// side of the string.
+pub fn trim_characters<'a, E>(
+    expression: E,
+    side: TrimSide,
+    characters: Option<Expression<'a>>,
+) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+{
+    let fun = Trim {
+        expression: Box::new(expression.into()),
+        side,
+        characters: characters.map(Box::new),
+    };
+
+    fun.into()
+}
+
+impl<'a> From<Trim<'a>> for Function<'a> {
+    fn from(value: Trim<'a>) -> Self {
+        Self {
+            typ_: FunctionType::Trim(value),
+            alias: None,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use anyhow::anyhow;
 
-use crate::ast::{Column, Expression, Query, Row, Table, Update, Values};
+use crate::ast::{Column, Comment, Expression, Query, Row, Table, Update, Values};
 
 /// A builder for an `INSERT` statement.
 #[derive(Clone, Debug, PartialEq)]
@@ -12,7 +12,7 @@ pub struct Insert<'a> {
     pub(crate) values: Expression<'a>,
     pub(crate) on_conflict: Option<OnConflict<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
-    pub(crate) comment: Option<Cow<'a, str>>,
+    pub(crate) comment: Comment<'a>,
 }
 
 /// A builder for an `INSERT` statement for a single row.
@@ -61,7 +61,7 @@ impl<'a> From<SingleRowInsert<'a>> for Insert<'a> {
             values,
             on_conflict: None,
             returning: None,
-            comment: None,
+            comment: Comment::default(),
         }
     }
 }
@@ -76,7 +76,7 @@ impl<'a> From<MultiRowInsert<'a>> for Insert<'a> {
             values,
             on_conflict: None,
             returning: None,
-            comment: None,
+            comment: Comment::default(),
         }
     }
 }
@@ -163,7 +163,7 @@ impl<'a> Insert<'a> {
             values: expression.into(),
             on_conflict: None,
             returning: None,
-            comment: None,
+            comment: Comment::default(),
         }
     }
 
@@ -174,7 +174,17 @@ impl<'a> Insert<'a> {
 
     /// Adds a comment to the insert.
     pub fn comment<C: Into<Cow<'a, str>>>(&mut self, comment: C) {
-        self.comment = Some(comment.into());
+        self.comment.set_text(comment);
+    }
+
+    /// Adds a key-value tag to the statement comment, rendered in the
+    /// sqlcommenter format.
+    pub fn tag<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.comment.push_tag(key, value);
     }
 
     /// Sets the returned columns.
@@ -291,4 +301,57 @@ impl<'a> MultiRowInsert<'a> {
     pub fn build(self) -> Insert<'a> {
         Insert::from(self)
     }
+
+    /// Splits the rows into several `Insert` statements, each using at most
+    /// `max_parameters` parameters. Every value is counted as one parameter.
+    /// Fails if a single row has more values than `max_parameters`.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, self}};
+    /// # fn main() {
+    /// let mut query = Insert::multi_into("users", ["foo", "bar"]);
+    ///
+    /// query.values((1, 2));
+    /// query.values((3, 4));
+    /// query.values((5, 6));
+    ///
+    /// let inserts = query.split(4).unwrap();
+    /// assert_eq!(2, inserts.len());
+    ///
+    /// let (sql, _) = renderer::Postgres::build(inserts[0].clone());
+    /// assert_eq!(r#"INSERT INTO "users" ("foo","bar") VALUES ($1,$2), ($3,$4)"#, sql);
+    ///
+    /// let (sql, _) = renderer::Postgres::build(inserts[1].clone());
+    /// assert_eq!(r#"INSERT INTO "users" ("foo","bar") VALUES ($1,$2)"#, sql);
+    /// # }
+    /// ```
+    pub fn split(self, max_parameters: usize) -> anyhow::Result<Vec<Insert<'a>>> {
+        if self.columns.len() > max_parameters {
+            return Err(anyhow!(
+                "A row has {} values, more than the maximum of {} parameters.",
+                self.columns.len(),
+                max_parameters
+            ));
+        }
+
+        if self.values.is_empty() {
+            return Ok(vec![self.build()]);
+        }
+
+        let rows_per_insert = (max_parameters / self.columns.len().max(1)).max(1);
+        let mut values = self.values.into_iter().peekable();
+        let mut inserts = Vec::new();
+
+        while values.peek().is_some() {
+            let insert = MultiRowInsert {
+                table: self.table.clone(),
+                columns: self.columns.clone(),
+                values: values.by_ref().take(rows_per_insert).collect(),
+            };
+
+            inserts.push(insert.build());
+        }
+
+        Ok(inserts)
+    }
 }
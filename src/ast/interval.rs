@@ -0,0 +1,68 @@
+use crate::ast::{Expression, ExpressionKind};
+
+/// A part of a date or a time, used in interval literals, `DATE_TRUNC` and
+/// `EXTRACT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeField {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateTimeField {
+    /// The field as a SQL keyword, e.g. `DAY`.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Second => "SECOND",
+            Self::Minute => "MINUTE",
+            Self::Hour => "HOUR",
+            Self::Day => "DAY",
+            Self::Week => "WEEK",
+            Self::Month => "MONTH",
+            Self::Quarter => "QUARTER",
+            Self::Year => "YEAR",
+        }
+    }
+}
+
+/// A fixed amount of time, such as seven days. Added to or subtracted from a
+/// timestamp expression with `+` and `-`.
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+/// # fn main() {
+/// let mut query = Select::from_table("posts");
+/// query.so_that("created_at".greater_than(Expression::from(now()) - interval(7, DateTimeField::Day)));
+///
+/// let (sql, _) = Postgres::build(query);
+///
+/// assert_eq!(
+///     r#"SELECT "posts".* FROM "posts" WHERE "created_at" > (CURRENT_TIMESTAMP - INTERVAL '7' DAY)"#,
+///     sql
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub(crate) quantity: i64,
+    pub(crate) field: DateTimeField,
+}
+
+/// An interval of `quantity` times the given field.
+pub fn interval(quantity: i64, field: DateTimeField) -> Interval {
+    Interval { quantity, field }
+}
+
+impl<'a> From<Interval> for Expression<'a> {
+    fn from(interval: Interval) -> Self {
+        Expression {
+            kind: ExpressionKind::Interval(interval),
+            alias: None,
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use crate::ast::Expression;
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use crate::ast::{Expression, Interval};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
 /// Calculation operations in SQL queries.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,6 +11,15 @@ pub enum SqlOp<'a> {
     Rem(Expression<'a>, Expression<'a>),
     Append(Expression<'a>, Expression<'a>),
     JsonDeleteAtPath(Expression<'a>, Expression<'a>),
+    AddInterval(Expression<'a>, Interval),
+    SubInterval(Expression<'a>, Interval),
+    BitAnd(Expression<'a>, Expression<'a>),
+    BitOr(Expression<'a>, Expression<'a>),
+    BitXor(Expression<'a>, Expression<'a>),
+    ShiftLeft(Expression<'a>, Expression<'a>),
+    ShiftRight(Expression<'a>, Expression<'a>),
+    Neg(Expression<'a>),
+    Not(Expression<'a>),
 }
 
 impl<'a> Add for Expression<'a> {
@@ -29,6 +38,22 @@ impl<'a> Sub for Expression<'a> {
     }
 }
 
+impl<'a> Add<Interval> for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn add(self, other: Interval) -> Self {
+        SqlOp::AddInterval(self, other).into()
+    }
+}
+
+impl<'a> Sub<Interval> for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn sub(self, other: Interval) -> Self {
+        SqlOp::SubInterval(self, other).into()
+    }
+}
+
 impl<'a> Mul for Expression<'a> {
     type Output = Expression<'a>;
 
@@ -52,3 +77,59 @@ impl<'a> Rem for Expression<'a> {
         SqlOp::Rem(self, other).into()
     }
 }
+
+impl<'a> BitAnd for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn bitand(self, other: Self) -> Self {
+        SqlOp::BitAnd(self, other).into()
+    }
+}
+
+impl<'a> BitOr for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn bitor(self, other: Self) -> Self {
+        SqlOp::BitOr(self, other).into()
+    }
+}
+
+impl<'a> BitXor for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn bitxor(self, other: Self) -> Self {
+        SqlOp::BitXor(self, other).into()
+    }
+}
+
+impl<'a> Shl for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn shl(self, other: Self) -> Self {
+        SqlOp::ShiftLeft(self, other).into()
+    }
+}
+
+impl<'a> Shr for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn shr(self, other: Self) -> Self {
+        SqlOp::ShiftRight(self, other).into()
+    }
+}
+
+impl<'a> Neg for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn neg(self) -> Self {
+        SqlOp::Neg(self).into()
+    }
+}
+
+impl<'a> Not for Expression<'a> {
+    type Output = Expression<'a>;
+
+    fn not(self) -> Self {
+        SqlOp::Not(self).into()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ast::{Column, Expression};
+use crate::ast::{Column, Expression, Function};
 
 /// Defines ordering for an `ORDER BY` statement.
 pub type OrderDefinition<'a> = (Expression<'a>, Option<Order>);
@@ -95,6 +95,12 @@ impl<'a> IntoOrderDefinition<'a> for Column<'a> {
     }
 }
 
+impl<'a> IntoOrderDefinition<'a> for Function<'a> {
+    fn into_order_definition(self) -> OrderDefinition<'a> {
+        (self.into(), None)
+    }
+}
+
 impl<'a> IntoOrderDefinition<'a> for OrderDefinition<'a> {
     fn into_order_definition(self) -> OrderDefinition<'a> {
         self
@@ -107,6 +113,12 @@ impl<'a> Orderable<'a> for Column<'a> {
     }
 }
 
+impl<'a> Orderable<'a> for Function<'a> {
+    fn order(self, order: Option<Order>) -> OrderDefinition<'a> {
+        (self.into(), order)
+    }
+}
+
 impl<'a> Orderable<'a> for &'a str {
     fn order(self, order: Option<Order>) -> OrderDefinition<'a> {
         let column: Column<'a> = self.into();
@@ -1,4 +1,6 @@
 use crate::ast::{Delete, Insert, Select, Update};
+#[cfg(feature = "postgresql")]
+use crate::renderer::Postgres;
 
 /// A database query
 #[derive(Debug, Clone, PartialEq)]
@@ -25,4 +27,38 @@ impl<'a> Query<'a> {
     pub fn is_delete(&self) -> bool {
         matches!(self, Query::Delete(_))
     }
+
+    /// The normalized SQL of the query, with parameter values, the length of
+    /// `IN` lists and comments left out. See
+    /// [Postgres::normalized_sql](../renderer/type.Postgres.html#method.normalized_sql).
+    #[cfg(feature = "postgresql")]
+    pub fn normalized_sql(&self) -> String {
+        Postgres::normalized_sql(self.clone())
+    }
+
+    /// A hash of the structure of the query, ignoring parameter values, the
+    /// length of `IN` lists and comments. The hash is stable across runs and
+    /// versions of Rust, so it can be stored, e.g. as a cache key.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::ast::*;
+    /// # fn main() {
+    /// let mut a = Select::from_table("users");
+    /// a.so_that("id".in_selection(Row::from(vec![1, 2])));
+    ///
+    /// let mut b = Select::from_table("users");
+    /// b.so_that("id".in_selection(Row::from(vec![3, 4, 5])));
+    ///
+    /// assert_eq!(Query::from(a).fingerprint(), Query::from(b).fingerprint());
+    /// # }
+    /// ```
+    #[cfg(feature = "postgresql")]
+    pub fn fingerprint(&self) -> u64 {
+        // 64-bit FNV-1a, fixed unlike the hashers of the standard library.
+        self.normalized_sql()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
 }
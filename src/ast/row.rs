@@ -2,7 +2,7 @@ use serde_json::Value;
 
 #[cfg(any(feature = "postgresql", feature = "mysql"))]
 use super::compare::JsonType;
-use crate::ast::{Comparable, Compare, Expression};
+use crate::ast::{Comparable, Compare, ComparisonOperator, Expression, Quantifier};
 use std::borrow::Cow;
 
 /// A collection of values surrounded by parentheses.
@@ -251,6 +251,112 @@ impl<'a> Comparable<'a> for Row<'a> {
         value.not_like(pattern)
     }
 
+    fn ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.ilike(pattern)
+    }
+
+    fn not_ilike<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.not_ilike(pattern)
+    }
+
+    fn regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.regex_match(pattern)
+    }
+
+    fn not_regex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.not_regex_match(pattern)
+    }
+
+    fn iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.iregex_match(pattern)
+    }
+
+    fn not_iregex_match<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.not_iregex_match(pattern)
+    }
+
+    fn similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.similar_to(pattern)
+    }
+
+    fn not_similar_to<T>(self, pattern: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.not_similar_to(pattern)
+    }
+
+    fn has_prefix<T>(self, prefix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.has_prefix(prefix)
+    }
+
+    fn has_suffix<T>(self, suffix: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.has_suffix(suffix)
+    }
+
+    fn has_substring<T>(self, substring: T) -> Compare<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.has_substring(substring)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.is_distinct_from(comparison)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_distinct_from<T>(self, comparison: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.is_not_distinct_from(comparison)
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn is_null(self) -> Compare<'a> {
         let value: Expression<'a> = self.into();
@@ -290,6 +396,19 @@ impl<'a> Comparable<'a> for Row<'a> {
         value.compare_raw(raw_comparator, right)
     }
 
+    fn quantified<T>(
+        self,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: T,
+    ) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.quantified(operator, quantifier, right)
+    }
+
     /// Tests if the array overlaps with another array.
     #[cfg(any(feature = "postgresql", feature = "mysql"))]
     fn array_overlaps<T>(self, item: T) -> Compare<'a>
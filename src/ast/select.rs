@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use super::{
-    Column, CommonTableExpression, ConditionTree, Expression, ExpressionKind, Grouping,
+    Column, Comment, CommonTableExpression, ConditionTree, Expression, ExpressionKind, Grouping,
     IntoGroupByDefinition, IntoOrderDefinition, Join, JoinData, Ordering, Query, Table,
 };
 
@@ -21,7 +21,7 @@ pub struct Select<'a> {
     pub(crate) limit: Option<u32>,
     pub(crate) offset: Option<u32>,
     pub(crate) joins: Vec<Join<'a>>,
-    pub(crate) comment: Option<Cow<'a, str>>,
+    pub(crate) comment: Comment<'a>,
 }
 
 impl<'a> From<Select<'a>> for Expression<'a> {
@@ -534,4 +534,47 @@ impl<'a> Select<'a> {
     pub fn with(&mut self, cte: CommonTableExpression<'a>) {
         self.ctes.push(cte);
     }
+
+    /// Adds a comment to the end of the statement.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, self}};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.comment("fetch all users");
+    ///
+    /// let (sql, _) = renderer::Postgres::build(query);
+    ///
+    /// assert_eq!(r#"SELECT "users".* FROM "users" /* fetch all users */"#, sql);
+    /// # }
+    /// ```
+    pub fn comment<C: Into<Cow<'a, str>>>(&mut self, comment: C) {
+        self.comment.set_text(comment);
+    }
+
+    /// Adds a key-value tag to the statement comment, rendered in the
+    /// sqlcommenter format.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, self}};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.tag("route", "/users/{id}");
+    /// query.tag("operation", "GetUser");
+    ///
+    /// let (sql, _) = renderer::Postgres::build(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" /*operation='GetUser',route='%2Fusers%2F%7Bid%7D'*/"#,
+    ///     sql
+    /// );
+    /// # }
+    /// ```
+    pub fn tag<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.comment.push_tag(key, value);
+    }
 }
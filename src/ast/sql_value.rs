@@ -0,0 +1,325 @@
+use serde_json::{Number, Value};
+use std::hash::{Hash, Hasher};
+
+use crate::ast::{Expression, ExpressionKind};
+
+/// A parameter value sent to the database together with the query. Unlike a
+/// JSON value, it keeps the type of the data, so the database driver knows how
+/// to bind it.
+///
+/// A `serde_json::Value` converts into the closest typed variant: integers
+/// become `Int64`, other numbers `Double` and strings `Text`. Arrays and
+/// objects stay JSON documents in `Json`. Going back, every value converts
+/// into a `serde_json::Value` with `into_json`, so code that expects JSON
+/// parameters can map them:
+///
+/// ```rust
+/// # use grafbase_sql_ast::{ast::*, renderer::{Renderer, Postgres}};
+/// # use serde_json::{json, Value};
+/// # fn main() {
+/// let mut query = Select::from_table("users");
+/// query.so_that("tags".array_contains(json!(["admin"])));
+///
+/// let (_, params) = Postgres::build(query);
+/// let params: Vec<Value> = params.into_iter().map(SqlValue::into_json).collect();
+///
+/// assert_eq!(vec![json!(["admin"])], params);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    /// `NULL`
+    Null,
+    /// A boolean value.
+    Boolean(bool),
+    /// A 16-bit integer.
+    Int16(i16),
+    /// A 32-bit integer.
+    Int32(i32),
+    /// A 64-bit integer.
+    Int64(i64),
+    /// A single precision floating point number.
+    Float(f32),
+    /// A double precision floating point number.
+    Double(f64),
+    /// An arbitrary precision number in its decimal text form, e.g. `12.50`.
+    Numeric(String),
+    /// A string value.
+    Text(String),
+    /// Binary data.
+    Bytes(Vec<u8>),
+    /// A UUID.
+    Uuid(u128),
+    /// A date in the ISO 8601 format, e.g. `2023-11-07`.
+    Date(String),
+    /// A timestamp without a time zone in the ISO 8601 format, e.g. `2023-11-07T10:00:00`.
+    Timestamp(String),
+    /// A timestamp with a time zone in the ISO 8601 format, e.g. `2023-11-07T10:00:00Z`.
+    TimestampTz(String),
+    /// A JSON document.
+    Json(Value),
+    /// An array of values.
+    Array(Vec<SqlValue>),
+    /// A value with an explicitly declared SQL type, overriding the type
+    /// inferred from the value.
+    Typed(Box<SqlValue>, SqlType),
+}
+
+/// The SQL type of a parameter value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SqlType {
+    Boolean,
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    DoublePrecision,
+    Numeric,
+    Text,
+    Bytes,
+    Uuid,
+    Date,
+    Timestamp,
+    TimestampTz,
+    Json,
+    Jsonb,
+    Array(Box<SqlType>),
+}
+
+impl SqlValue {
+    /// Declares the SQL type of the value.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::ast::*;
+    /// # fn main() {
+    /// let value = SqlValue::Null.with_type(SqlType::BigInt);
+    /// assert_eq!(Some(SqlType::BigInt), value.sql_type());
+    /// # }
+    /// ```
+    pub fn with_type(self, sql_type: SqlType) -> Self {
+        match self {
+            SqlValue::Typed(value, _) => SqlValue::Typed(value, sql_type),
+            value => SqlValue::Typed(Box::new(value), sql_type),
+        }
+    }
+
+    /// The declared type of the value, or the type inferred from the value.
+    /// `None` for `NULL` values, arrays without any non-null elements and JSON
+    /// documents, which can be either `json`, `jsonb` or a text array in the
+    /// query.
+    pub fn sql_type(&self) -> Option<SqlType> {
+        match self {
+            SqlValue::Null => None,
+            SqlValue::Boolean(_) => Some(SqlType::Boolean),
+            SqlValue::Int16(_) => Some(SqlType::SmallInt),
+            SqlValue::Int32(_) => Some(SqlType::Integer),
+            SqlValue::Int64(_) => Some(SqlType::BigInt),
+            SqlValue::Float(_) => Some(SqlType::Real),
+            SqlValue::Double(_) => Some(SqlType::DoublePrecision),
+            SqlValue::Numeric(_) => Some(SqlType::Numeric),
+            SqlValue::Text(_) => Some(SqlType::Text),
+            SqlValue::Bytes(_) => Some(SqlType::Bytes),
+            SqlValue::Uuid(_) => Some(SqlType::Uuid),
+            SqlValue::Date(_) => Some(SqlType::Date),
+            SqlValue::Timestamp(_) => Some(SqlType::Timestamp),
+            SqlValue::TimestampTz(_) => Some(SqlType::TimestampTz),
+            SqlValue::Json(_) => None,
+            SqlValue::Array(values) => values
+                .iter()
+                .find_map(SqlValue::sql_type)
+                .map(|element| SqlType::Array(Box::new(element))),
+            SqlValue::Typed(_, sql_type) => Some(sql_type.clone()),
+        }
+    }
+
+    /// Converts the value into JSON, e.g. for callers that bind parameters as
+    /// `serde_json::Value`. Bytes become a `\x` prefixed hex string and UUIDs
+    /// their hyphenated form.
+    pub fn into_json(self) -> Value {
+        match self {
+            SqlValue::Null => Value::Null,
+            SqlValue::Boolean(b) => Value::Bool(b),
+            SqlValue::Int16(i) => Value::from(i),
+            SqlValue::Int32(i) => Value::from(i),
+            SqlValue::Int64(i) => Value::from(i),
+            SqlValue::Float(f) => Value::from(f),
+            SqlValue::Double(f) => Value::from(f),
+            SqlValue::Numeric(n) => match n.parse::<Number>() {
+                Ok(number) => Value::Number(number),
+                Err(_) => Value::String(n),
+            },
+            SqlValue::Text(s)
+            | SqlValue::Date(s)
+            | SqlValue::Timestamp(s)
+            | SqlValue::TimestampTz(s) => Value::String(s),
+            SqlValue::Bytes(bytes) => {
+                let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                Value::String(format!("\\x{hex}"))
+            }
+            SqlValue::Uuid(uuid) => Value::String(format_uuid(uuid)),
+            SqlValue::Json(json) => json,
+            SqlValue::Array(values) => {
+                Value::Array(values.into_iter().map(SqlValue::into_json).collect())
+            }
+            SqlValue::Typed(value, _) => value.into_json(),
+        }
+    }
+
+    /// Converts the value for a type the renderer expects, returning the
+    /// value with the type if it fits, or unchanged and without a type if it
+    /// does not.
+    pub(crate) fn coerce(self, sql_type: SqlType) -> (SqlValue, Option<SqlType>) {
+        let value = match (self, &sql_type) {
+            (SqlValue::Null, _) => SqlValue::Null,
+            (value @ SqlValue::Json(_), SqlType::Json | SqlType::Jsonb) => value,
+            (value, SqlType::Json | SqlType::Jsonb) => SqlValue::Json(value.into_json()),
+            (SqlValue::Int16(i), SqlType::Integer) => SqlValue::Int32(i32::from(i)),
+            (SqlValue::Int64(i), SqlType::Integer) => match i32::try_from(i) {
+                Ok(i) => SqlValue::Int32(i),
+                Err(_) => return (SqlValue::Int64(i), None),
+            },
+            (value, _) if value.sql_type().as_ref() == Some(&sql_type) => value,
+            (value, _) => return (value, None),
+        };
+
+        (value, Some(sql_type))
+    }
+
+    /// Feeds the value into the hasher, so that equal values hash equally.
+    /// Values are not `Hash` themselves, because floats and JSON are not.
+    pub(crate) fn hash_into<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            SqlValue::Null => (),
+            SqlValue::Boolean(b) => b.hash(state),
+            SqlValue::Int16(i) => i.hash(state),
+            SqlValue::Int32(i) => i.hash(state),
+            SqlValue::Int64(i) => i.hash(state),
+            // Zero and negative zero are equal, but have different bits.
+            SqlValue::Float(f) => (*f != 0.0).then(|| f.to_bits()).hash(state),
+            SqlValue::Double(f) => (*f != 0.0).then(|| f.to_bits()).hash(state),
+            SqlValue::Numeric(s)
+            | SqlValue::Text(s)
+            | SqlValue::Date(s)
+            | SqlValue::Timestamp(s)
+            | SqlValue::TimestampTz(s) => s.hash(state),
+            SqlValue::Bytes(bytes) => bytes.hash(state),
+            SqlValue::Uuid(uuid) => uuid.hash(state),
+            SqlValue::Json(json) => hash_json(json, state),
+            SqlValue::Array(values) => {
+                values.len().hash(state);
+
+                for value in values {
+                    value.hash_into(state);
+                }
+            }
+            SqlValue::Typed(value, sql_type) => {
+                value.hash_into(state);
+                sql_type.hash(state);
+            }
+        }
+    }
+
+    /// True if the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        match self {
+            SqlValue::Null => true,
+            SqlValue::Typed(value, _) => value.is_null(),
+            _ => false,
+        }
+    }
+}
+
+impl From<Value> for SqlValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => SqlValue::Null,
+            Value::Bool(b) => SqlValue::Boolean(b),
+            Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+                (Some(i), _) if n.is_i64() => SqlValue::Int64(i),
+                (_, Some(f)) if n.is_f64() => SqlValue::Double(f),
+                _ => SqlValue::Numeric(n.to_string()),
+            },
+            Value::String(s) => SqlValue::Text(s),
+            value @ (Value::Array(_) | Value::Object(_)) => SqlValue::Json(value),
+        }
+    }
+}
+
+impl PartialEq<Value> for SqlValue {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (SqlValue::Null, Value::Null) => true,
+            (SqlValue::Boolean(a), Value::Bool(b)) => a == b,
+            (SqlValue::Int16(a), Value::Number(b)) => {
+                b.is_i64() && b.as_i64() == Some(i64::from(*a))
+            }
+            (SqlValue::Int32(a), Value::Number(b)) => {
+                b.is_i64() && b.as_i64() == Some(i64::from(*a))
+            }
+            (SqlValue::Int64(a), Value::Number(b)) => b.is_i64() && b.as_i64() == Some(*a),
+            (SqlValue::Float(a), Value::Number(b)) => {
+                b.is_f64() && b.as_f64() == Some(f64::from(*a))
+            }
+            (SqlValue::Double(a), Value::Number(b)) => b.is_f64() && b.as_f64() == Some(*a),
+            (SqlValue::Numeric(a), Value::Number(b)) => {
+                !b.is_i64() && !b.is_f64() && *a == b.to_string()
+            }
+            (SqlValue::Text(a), Value::String(b)) => a == b,
+            (SqlValue::Array(a), Value::Array(b)) => a == b,
+            (SqlValue::Json(a), b) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<SqlValue> for Value {
+    fn eq(&self, other: &SqlValue) -> bool {
+        other == self
+    }
+}
+
+/// Hashes the JSON value. Objects only hash their size, so the result does
+/// not depend on the order of the keys.
+fn hash_json<H: Hasher>(json: &Value, state: &mut H) {
+    std::mem::discriminant(json).hash(state);
+
+    match json {
+        Value::Null => (),
+        Value::Bool(b) => b.hash(state),
+        Value::Number(n) => n.as_f64().map(f64::to_bits).hash(state),
+        Value::String(s) => s.hash(state),
+        Value::Array(values) => {
+            values.len().hash(state);
+
+            for value in values {
+                hash_json(value, state);
+            }
+        }
+        Value::Object(map) => map.len().hash(state),
+    }
+}
+
+/// Formats the UUID in its hyphenated form.
+pub(crate) fn format_uuid(uuid: u128) -> String {
+    let hex = format!("{uuid:032x}");
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+impl<'a> From<SqlValue> for Expression<'a> {
+    fn from(value: SqlValue) -> Self {
+        Expression {
+            kind: ExpressionKind::Parameterized(value),
+            alias: None,
+        }
+    }
+}
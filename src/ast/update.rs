@@ -1,4 +1,5 @@
-use crate::ast::{Column, ConditionTree, Expression, Query, Table};
+use crate::ast::{Column, Comment, ConditionTree, Expression, Query, Table};
+use std::borrow::Cow;
 
 type Type<'a> = Column<'a>;
 
@@ -10,6 +11,7 @@ pub struct Update<'a> {
     pub(crate) values: Vec<Expression<'a>>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) returning: Option<Vec<Type<'a>>>,
+    pub(crate) comment: Comment<'a>,
 }
 
 impl<'a> From<Update<'a>> for Query<'a> {
@@ -30,6 +32,7 @@ impl<'a> Update<'a> {
             values: Vec::new(),
             conditions: None,
             returning: None,
+            comment: Comment::default(),
         }
     }
 
@@ -145,4 +148,19 @@ impl<'a> Update<'a> {
     {
         self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
     }
+
+    /// Adds a comment to the end of the statement.
+    pub fn comment<C: Into<Cow<'a, str>>>(&mut self, comment: C) {
+        self.comment.set_text(comment);
+    }
+
+    /// Adds a key-value tag to the statement comment, rendered in the
+    /// sqlcommenter format.
+    pub fn tag<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.comment.push_tag(key, value);
+    }
 }
@@ -4,16 +4,125 @@
 //! The visitor module should not know how to construct an AST, just how to read
 //! one. Everything related to the tree generation is in the
 //! [ast](../ast/index.html) module.
+#[cfg(feature = "mysql")]
+mod mysql;
 #[cfg(feature = "postgresql")]
 mod postgres;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod template;
 
-use serde_json::Value;
+use anyhow::anyhow;
 
+#[cfg(feature = "mysql")]
+pub use self::mysql::Mysql;
 #[cfg(feature = "postgresql")]
-pub use self::postgres::Postgres;
+pub use self::postgres::{Postgres, PostgresRenderer, StrictPostgres};
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::Sqlite;
+pub use self::template::Template;
 
 use crate::ast::*;
-use std::fmt;
+use std::{borrow::Cow, fmt};
+
+/// Keywords that cannot be used as a function name without quoting, in
+/// alphabetical order.
+const RESERVED_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "between",
+    "both",
+    "by",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "delete",
+    "desc",
+    "distinct",
+    "do",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "initially",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "lateral",
+    "leading",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "select",
+    "session_user",
+    "set",
+    "some",
+    "symmetric",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "variadic",
+    "when",
+    "where",
+    "window",
+    "with",
+];
 
 /// A function travelling through the query AST, building the final query string
 /// and gathering parameters sent to the database together with the query.
@@ -24,14 +133,38 @@ pub trait Renderer<'a> {
     const C_BACKTICK_CLOSE: &'static str;
     /// Wildcard character to be used in `LIKE` queries.
     const C_WILDCARD: &'static str;
+    /// Escape character for wildcards in `LIKE` patterns.
+    const C_LIKE_ESCAPE: char = '!';
+    /// The maximum number of bind parameters the database accepts in one statement.
+    /// Unlimited by default.
+    const MAX_PARAMETERS: usize = usize::MAX;
 
     /// Convert the given `Query` to an SQL string and a vector of parameters.
     /// When certain parameters are replaced with the `C_PARAM` character in the
     /// query, the vector should contain the parameter value in the right position.
-    fn build<Q>(query: Q) -> (String, Vec<Value>)
+    fn build<Q>(query: Q) -> (String, Vec<SqlValue>)
     where
         Q: Into<Query<'a>>;
 
+    /// Like `build`, but returns an error if the query needs more parameters
+    /// than the database accepts, instead of failing when executed.
+    fn try_build<Q>(query: Q) -> anyhow::Result<(String, Vec<SqlValue>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (sql, params) = Self::build(query);
+
+        if params.len() > Self::MAX_PARAMETERS {
+            return Err(anyhow!(
+                "The query has {} parameters, more than the maximum of {}.",
+                params.len(),
+                Self::MAX_PARAMETERS
+            ));
+        }
+
+        Ok((sql, params))
+    }
+
     /// Write to the query.
     fn write<D: fmt::Display>(&mut self, s: D);
 
@@ -50,6 +183,31 @@ pub trait Renderer<'a> {
         self.write(end)
     }
 
+    /// Writes the start of a clause, such as `WHERE ..`, separated from the
+    /// previous clause.
+    fn write_clause(&mut self, clause: &str) {
+        self.write(" ");
+        self.write(clause);
+    }
+
+    /// Writes the clauses of `f` one level deeper, e.g. the conditions of a
+    /// join.
+    fn indented<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        f(self)
+    }
+
+    /// Surrounds a nested query, such as a subquery or a CTE body, with
+    /// parentheses.
+    fn visit_nested<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.surround_with("(", ")", f)
+    }
+
     fn columns_to_bracket_list(&mut self, columns: Vec<Column<'a>>) {
         let len = columns.len();
 
@@ -66,7 +224,7 @@ pub trait Renderer<'a> {
 
     /// When called, the visitor decided to not render the parameter into the query,
     /// replacing it with the `C_PARAM`, calling `add_parameter` with the replaced value.
-    fn add_parameter(&mut self, value: Value);
+    fn add_parameter(&mut self, value: SqlValue);
 
     /// The `LIMIT` and `OFFSET` statement in the query
     fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>);
@@ -122,8 +280,15 @@ pub trait Renderer<'a> {
     /// A walk through an `DELETE` statement
     fn visit_delete(&mut self, delete: Delete<'a>);
 
+    /// A named placeholder, bound to a value after rendering. Renderers
+    /// without named placeholders bind `NULL` in its place.
+    fn visit_placeholder(&mut self, name: Cow<'a, str>) {
+        let _ = name;
+        self.visit_parameterized(SqlValue::Null)
+    }
+
     /// A visit to a value we parameterize
-    fn visit_parameterized(&mut self, value: Value) {
+    fn visit_parameterized(&mut self, value: SqlValue) {
         self.add_parameter(value);
         self.parameter_substitution()
     }
@@ -133,19 +298,19 @@ pub trait Renderer<'a> {
         for j in joins {
             match j {
                 Join::Inner(data) => {
-                    self.write(" INNER JOIN ");
+                    self.write_clause("INNER JOIN ");
                     self.visit_join_data(data);
                 }
                 Join::Left(data) => {
-                    self.write(" LEFT JOIN ");
+                    self.write_clause("LEFT JOIN ");
                     self.visit_join_data(data);
                 }
                 Join::Right(data) => {
-                    self.write(" RIGHT JOIN ");
+                    self.write_clause("RIGHT JOIN ");
                     self.visit_join_data(data);
                 }
                 Join::Full(data) => {
-                    self.write(" FULL JOIN ");
+                    self.write_clause("FULL JOIN ");
                     self.visit_join_data(data);
                 }
             }
@@ -154,7 +319,7 @@ pub trait Renderer<'a> {
 
     fn visit_join_data(&mut self, data: JoinData<'a>) {
         self.visit_table(data.table, true);
-        self.write(" ON ");
+        self.indented(|s| s.write_clause("ON "));
         self.visit_conditions(data.conditions)
     }
 
@@ -163,7 +328,7 @@ pub trait Renderer<'a> {
         self.write(" AS ");
 
         let query = cte.query;
-        self.surround_with("(", ")", |ref mut s| s.visit_query(query));
+        self.visit_nested(|s| s.visit_query(query));
     }
 
     /// A walk through a `SELECT` statement
@@ -181,11 +346,11 @@ pub trait Renderer<'a> {
                 }
             }
 
-            self.write(" ");
+            self.write_clause("SELECT ");
+        } else {
+            self.write("SELECT ");
         }
 
-        self.write("SELECT ");
-
         if select.distinct {
             self.write("DISTINCT ");
         }
@@ -200,22 +365,14 @@ pub trait Renderer<'a> {
                     match &table.typ {
                         TableType::Query(_) | TableType::Values(_) => match table.alias {
                             Some(ref alias) => {
-                                self.surround_with(
-                                    Self::C_BACKTICK_OPEN,
-                                    Self::C_BACKTICK_CLOSE,
-                                    |ref mut s| s.write(alias),
-                                );
+                                self.surround_with_backticks(alias);
                                 self.write(".*");
                             }
                             None => self.write("*"),
                         },
                         TableType::Table(_) => match table.alias.clone() {
                             Some(ref alias) => {
-                                self.surround_with(
-                                    Self::C_BACKTICK_OPEN,
-                                    Self::C_BACKTICK_CLOSE,
-                                    |ref mut s| s.write(alias),
-                                );
+                                self.surround_with_backticks(alias);
                                 self.write(".*");
                             }
                             None => {
@@ -225,11 +382,7 @@ pub trait Renderer<'a> {
                         },
                         TableType::JoinedTable(jt) => match table.alias.clone() {
                             Some(ref alias) => {
-                                self.surround_with(
-                                    Self::C_BACKTICK_OPEN,
-                                    Self::C_BACKTICK_CLOSE,
-                                    |ref mut s| s.write(alias),
-                                );
+                                self.surround_with_backticks(alias);
                                 self.write(".*");
                             }
                             None => {
@@ -248,7 +401,7 @@ pub trait Renderer<'a> {
                 self.visit_columns(select.columns);
             }
 
-            self.write(" FROM ");
+            self.write_clause("FROM ");
 
             for (i, table) in select.tables.into_iter().enumerate() {
                 if i > 0 {
@@ -263,19 +416,19 @@ pub trait Renderer<'a> {
             }
 
             if let Some(conditions) = select.conditions {
-                self.write(" WHERE ");
+                self.write_clause("WHERE ");
                 self.visit_conditions(conditions);
             }
             if !select.grouping.is_empty() {
-                self.write(" GROUP BY ");
+                self.write_clause("GROUP BY ");
                 self.visit_grouping(select.grouping);
             }
             if let Some(conditions) = select.having {
-                self.write(" HAVING ");
+                self.write_clause("HAVING ");
                 self.visit_conditions(conditions);
             }
             if !select.ordering.is_empty() {
-                self.write(" ORDER BY ");
+                self.write_clause("ORDER BY ");
                 self.visit_ordering(select.ordering);
             }
 
@@ -285,6 +438,8 @@ pub trait Renderer<'a> {
         } else {
             self.visit_columns(select.columns);
         }
+
+        self.visit_comment(select.comment);
     }
 
     /// A walk through an `UPDATE` statement
@@ -293,7 +448,7 @@ pub trait Renderer<'a> {
         self.visit_table(update.table, true);
 
         {
-            self.write(" SET ");
+            self.write_clause("SET ");
             let pairs = update.columns.into_iter().zip(update.values);
             let len = pairs.len();
 
@@ -309,17 +464,19 @@ pub trait Renderer<'a> {
         }
 
         if let Some(conditions) = update.conditions {
-            self.write(" WHERE ");
+            self.write_clause("WHERE ");
             self.visit_conditions(conditions);
         }
 
         if let Some(returning) = update.returning {
             if !returning.is_empty() {
                 let values = returning.into_iter().map(|r| r.into()).collect();
-                self.write(" RETURNING ");
+                self.write_clause("RETURNING ");
                 self.visit_columns(values);
             }
         }
+
+        self.visit_comment(update.comment);
     }
 
     fn visit_upsert(&mut self, update: Update<'a>) {
@@ -349,6 +506,49 @@ pub trait Renderer<'a> {
         }
     }
 
+    /// True if the renderer writes the normalized form of the query, which
+    /// leaves out parameter values and comments, identifying queries of the
+    /// same shape.
+    fn is_normalizing(&self) -> bool {
+        false
+    }
+
+    /// A comment at the end of a statement. The text is written in a block
+    /// comment with any comment delimiters broken up, and the tags follow in
+    /// the sqlcommenter format: sorted, URL-encoded `key='value'` pairs.
+    fn visit_comment(&mut self, comment: Comment<'a>) {
+        if self.is_normalizing() {
+            return;
+        }
+
+        if let Some(text) = comment.text {
+            self.write(" /* ");
+            self.write(text.replace("*/", "* /").replace("/*", "/ *"));
+            self.write(" */");
+        }
+
+        if !comment.tags.is_empty() {
+            let mut tags = comment.tags;
+            tags.sort();
+
+            self.write(" /*");
+
+            let len = tags.len();
+            for (i, (key, value)) in tags.into_iter().enumerate() {
+                self.write(url_encode(&key));
+                self.write("='");
+                self.write(url_encode(&value));
+                self.write("'");
+
+                if i < (len - 1) {
+                    self.write(",");
+                }
+            }
+
+            self.write("*/");
+        }
+    }
+
     /// A helper for delimiting an identifier, surrounding every part with `C_BACKTICK`
     /// and delimiting the values with a `.`
     fn delimited_identifiers(&mut self, parts: &[&str]) {
@@ -363,12 +563,21 @@ pub trait Renderer<'a> {
         }
     }
 
-    /// A helper for delimiting a part of an identifier, surrounding it with `C_BACKTICK`
+    /// A helper for delimiting a part of an identifier, surrounding it with `C_BACKTICK`.
+    /// Any closing backtick inside the part is doubled, so the identifier cannot
+    /// terminate early.
     fn surround_with_backticks(&mut self, part: &str) {
         self.surround_with(
             Self::C_BACKTICK_OPEN,
             Self::C_BACKTICK_CLOSE,
-            |ref mut s| s.write(part),
+            |ref mut s| {
+                if part.contains(Self::C_BACKTICK_CLOSE) {
+                    let escaped = Self::C_BACKTICK_CLOSE.repeat(2);
+                    s.write(part.replace(Self::C_BACKTICK_CLOSE, &escaped))
+                } else {
+                    s.write(part)
+                }
+            },
         );
     }
 
@@ -434,21 +643,74 @@ pub trait Renderer<'a> {
                 se.write(" #- ");
                 se.visit_expression(right);
             }),
+            SqlOp::AddInterval(left, interval) => {
+                self.visit_interval_arithmetic(left, interval, false)
+            }
+            SqlOp::SubInterval(left, interval) => {
+                self.visit_interval_arithmetic(left, interval, true)
+            }
+            SqlOp::BitAnd(left, right) => self.surround_with("(", ")", |ref mut se| {
+                se.visit_expression(left);
+                se.write(" & ");
+                se.visit_expression(right);
+            }),
+            SqlOp::BitOr(left, right) => self.surround_with("(", ")", |ref mut se| {
+                se.visit_expression(left);
+                se.write(" | ");
+                se.visit_expression(right);
+            }),
+            SqlOp::BitXor(left, right) => self.visit_bitwise_xor(left, right),
+            SqlOp::ShiftLeft(left, right) => self.surround_with("(", ")", |ref mut se| {
+                se.visit_expression(left);
+                se.write(" << ");
+                se.visit_expression(right);
+            }),
+            SqlOp::ShiftRight(left, right) => self.surround_with("(", ")", |ref mut se| {
+                se.visit_expression(left);
+                se.write(" >> ");
+                se.visit_expression(right);
+            }),
+            SqlOp::Neg(expression) => {
+                // Parenthesized, so a negative operand does not start a `--`
+                // comment.
+                self.write("-");
+                self.surround_with("(", ")", |ref mut se| se.visit_expression(expression));
+            }
+            SqlOp::Not(expression) => self.surround_with("(", ")", |ref mut se| {
+                se.write("NOT ");
+                se.visit_expression(expression);
+            }),
         }
     }
 
+    /// A bitwise XOR with the `^` operator of MySQL.
+    fn visit_bitwise_xor(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(" ^ ");
+            s.visit_expression(right);
+        });
+    }
+
     /// A visit to a value used in an expression
     fn visit_expression(&mut self, value: Expression<'a>) {
         match value.kind {
             ExpressionKind::Value(value) => self.visit_expression(*value),
-            ExpressionKind::Raw(value) => self.write(value),
+            ExpressionKind::Raw(value) => {
+                self.register_raw_fragment(value);
+                self.write(value)
+            }
+            ExpressionKind::RawWithParams(value, params) => {
+                self.visit_raw_with_params(value, params)
+            }
             ExpressionKind::ConditionTree(tree) => self.visit_conditions(tree),
             ExpressionKind::Compare(compare) => self.visit_compare(compare),
             ExpressionKind::Parameterized(val) => self.visit_parameterized(val),
+            ExpressionKind::Placeholder(name) => self.visit_placeholder(name),
             ExpressionKind::Column(column) => self.visit_column(*column),
             ExpressionKind::Row(row) => self.visit_row(row),
             ExpressionKind::Selection(selection) => {
-                self.surround_with("(", ")", |ref mut s| s.visit_select(*selection))
+                self.visit_nested(|s| s.visit_select(*selection))
             }
             ExpressionKind::Function(function) => self.visit_function(*function),
             ExpressionKind::Op(op) => self.visit_operation(*op),
@@ -461,6 +723,7 @@ pub trait Renderer<'a> {
                 None => self.write("*"),
             },
             ExpressionKind::Default => self.write("DEFAULT"),
+            ExpressionKind::Interval(interval) => self.visit_interval(interval),
             ExpressionKind::Table(table) => self.visit_table(*table, false),
         }
 
@@ -471,6 +734,48 @@ pub trait Renderer<'a> {
         };
     }
 
+    /// Called for every raw SQL fragment written to the query, to audit or
+    /// reject them.
+    fn register_raw_fragment(&mut self, _fragment: &str) {}
+
+    /// A raw fragment, with every `?` replaced by the next expression and
+    /// every `??` by a question mark.
+    fn visit_raw_with_params(&mut self, value: &'a str, params: Vec<Expression<'a>>) {
+        self.register_raw_fragment(value);
+
+        let mut params = params.into_iter();
+        let mut chars = value.char_indices().peekable();
+        let mut start = 0;
+
+        while let Some((i, c)) = chars.next() {
+            if c != '?' {
+                continue;
+            }
+
+            self.write(&value[start..i]);
+
+            if chars.next_if(|(_, c)| *c == '?').is_some() {
+                self.write("?");
+                start = i + 2;
+            } else {
+                if let Some(param) = params.next() {
+                    self.visit_expression(param);
+                }
+
+                start = i + 1;
+            }
+        }
+
+        self.write(&value[start..]);
+    }
+
+    /// An `IN` or `NOT IN` comparison against a list of values.
+    fn visit_in_list(&mut self, left: Expression<'a>, list: Row<'a>, negate: bool) {
+        self.visit_expression(left);
+        self.write(if negate { " NOT IN " } else { " IN " });
+        self.visit_row(list)
+    }
+
     fn visit_multiple_tuple_comparison(&mut self, left: Row<'a>, right: Values<'a>, negate: bool) {
         self.visit_row(left);
         self.write(if negate { " NOT IN " } else { " IN " });
@@ -498,9 +803,7 @@ pub trait Renderer<'a> {
                 None => self.delimited_identifiers(&[&*table_name]),
             },
             TableType::Values(values) => self.visit_values(values),
-            TableType::Query(select) => {
-                self.surround_with("(", ")", |ref mut s| s.visit_select(*select))
-            }
+            TableType::Query(select) => self.visit_nested(|s| s.visit_select(*select)),
             TableType::JoinedTable(jt) => {
                 match table.database {
                     Some(database) => self.delimited_identifiers(&[&*database, &*jt.0]),
@@ -582,13 +885,14 @@ pub trait Renderer<'a> {
             ConditionTree::Single(expression) => self.visit_expression(*expression),
             ConditionTree::NoCondition => self.write("1=1"),
             ConditionTree::NegativeCondition => self.write("1=0"),
-            ConditionTree::Exists(table) => self.surround_with("(", ")", |ref mut s| {
-                s.write("EXISTS ");
-
-                s.surround_with("(", ")", |ref mut s| {
-                    s.visit_table(*table, false);
-                })
-            }),
+            ConditionTree::Exists(select) => {
+                self.write("EXISTS ");
+                self.visit_nested(|s| s.visit_select(*select))
+            }
+            ConditionTree::NotExists(select) => {
+                self.write("NOT EXISTS ");
+                self.visit_nested(|s| s.visit_select(*select))
+            }
         }
     }
 
@@ -628,6 +932,213 @@ pub trait Renderer<'a> {
         self.visit_expression(right);
     }
 
+    /// A case-insensitive `LIKE`, comparing both sides in lower case for
+    /// databases without `ILIKE`.
+    fn visit_ilike(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.write("LOWER");
+        self.surround_with("(", ")", |s| s.visit_expression(left));
+        self.write(if not { " NOT LIKE " } else { " LIKE " });
+        self.write("LOWER");
+        self.surround_with("(", ")", |s| s.visit_expression(right));
+    }
+
+    /// A regular expression match with the `REGEXP` operator. A
+    /// case-insensitive match compares both sides in lower case, so the
+    /// pattern is lowercased too.
+    fn visit_regex_match(
+        &mut self,
+        left: Expression<'a>,
+        right: Expression<'a>,
+        case_insensitive: bool,
+        not: bool,
+    ) {
+        let operator = if not { " NOT REGEXP " } else { " REGEXP " };
+
+        if case_insensitive {
+            self.write("LOWER");
+            self.surround_with("(", ")", |s| s.visit_expression(left));
+            self.write(operator);
+            self.write("LOWER");
+            self.surround_with("(", ")", |s| s.visit_expression(right));
+        } else {
+            self.visit_expression(left);
+            self.write(operator);
+            self.visit_expression(right);
+        }
+    }
+
+    fn visit_similar_to(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.visit_expression(left);
+        self.write(if not {
+            " NOT SIMILAR TO "
+        } else {
+            " SIMILAR TO "
+        });
+        self.visit_expression(right);
+    }
+
+    /// A `LIKE` comparison matching the value literally, with a wildcard
+    /// before and/or after it. The wildcards and the escape character in the
+    /// value are escaped with `C_LIKE_ESCAPE`.
+    fn visit_like_escaped(
+        &mut self,
+        left: Expression<'a>,
+        value: Cow<'a, str>,
+        wildcard_before: bool,
+        wildcard_after: bool,
+    ) {
+        let mut pattern = String::with_capacity(value.len() + 2);
+
+        if wildcard_before {
+            pattern.push_str(Self::C_WILDCARD);
+        }
+
+        for c in value.chars() {
+            if matches!(c, '%' | '_') || c == Self::C_LIKE_ESCAPE {
+                pattern.push(Self::C_LIKE_ESCAPE);
+            }
+
+            pattern.push(c);
+        }
+
+        if wildcard_after {
+            pattern.push_str(Self::C_WILDCARD);
+        }
+
+        self.visit_like(left, Expression::from(pattern));
+        self.write_like_escape();
+    }
+
+    /// The `ESCAPE` clause declaring `C_LIKE_ESCAPE`, for databases where
+    /// it is not the default escape character.
+    fn write_like_escape(&mut self) {
+        self.write(format!(" ESCAPE '{}'", Self::C_LIKE_ESCAPE));
+    }
+
+    /// A null-safe comparison, rendered with the SQL standard `IS DISTINCT
+    /// FROM`. Overridden by renderers with their own null-safe operators.
+    fn visit_distinct_from(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.visit_expression(left);
+        self.write(if not {
+            " IS NOT DISTINCT FROM "
+        } else {
+            " IS DISTINCT FROM "
+        });
+        self.visit_expression(right);
+    }
+
+    /// A quantified comparison. For databases without `ANY` and `ALL`, a
+    /// subquery selecting one value is rewritten to `EXISTS`, moving the
+    /// comparison into its conditions:
+    ///
+    /// - `x > ANY (SELECT v ..)` to `EXISTS (SELECT .. WHERE x > v)`
+    /// - `x > ALL (SELECT v ..)` to
+    ///   `NOT EXISTS (SELECT .. WHERE (NOT (x > v) OR x IS NULL OR v IS NULL))`
+    ///
+    /// If the subquery selects something else than a column, e.g. an
+    /// aggregate, or is grouped or limited, it is moved into a derived table
+    /// `quantified`, selecting the value as `value`.
+    ///
+    /// The rewrite is `TRUE` and `FALSE` where the native comparison is, and
+    /// `FALSE` where it is `NULL`. It is only done if the left side cannot be
+    /// resolved in the subquery: parameters, and columns qualified with a
+    /// table not selected in the subquery. Anything else is rendered with
+    /// `visit_native_quantified`.
+    fn visit_quantified(
+        &mut self,
+        left: Expression<'a>,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: Expression<'a>,
+    ) {
+        let mut select = match right.kind {
+            ExpressionKind::Selection(select)
+                if select.columns.len() == 1 && is_outer_reference(&left, &select.tables) =>
+            {
+                select
+            }
+            _ => return self.visit_native_quantified(left, operator, quantifier, right),
+        };
+
+        let mut value = select.columns.pop().unwrap();
+        value.alias = None;
+
+        let is_column = match value.kind {
+            ExpressionKind::Column(ref mut column) => {
+                column.alias = None;
+                true
+            }
+            ExpressionKind::Function(ref mut function) => {
+                function.alias = None;
+                false
+            }
+            _ => false,
+        };
+
+        let (mut select, value) = if is_column
+            && select.grouping.is_empty()
+            && select.having.is_none()
+            && select.limit.is_none()
+            && select.offset.is_none()
+        {
+            (*select, value)
+        } else {
+            select.columns.push(value.alias("value"));
+
+            let derived = Select::from_table(Table::from(*select).alias("quantified"));
+            (
+                derived,
+                Expression::from(Column::from(("quantified", "value"))),
+            )
+        };
+
+        let condition = match quantifier {
+            Quantifier::Any | Quantifier::Some => {
+                select.and_where(operator.compare(left, value));
+                ConditionTree::exists(select)
+            }
+            Quantifier::All => {
+                let unknown = ConditionTree::not(operator.compare(left.clone(), value.clone()))
+                    .or(left.is_null())
+                    .or(value.is_null());
+
+                select.and_where(unknown);
+                ConditionTree::not_exists(select)
+            }
+        };
+
+        self.visit_conditions(condition)
+    }
+
+    /// A quantified comparison with the SQL standard `ANY`, `SOME` or `ALL`.
+    fn visit_native_quantified(
+        &mut self,
+        left: Expression<'a>,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: Expression<'a>,
+    ) {
+        self.visit_expression(left);
+        self.write(" ");
+        self.write(operator.as_str());
+
+        match quantifier {
+            Quantifier::Any => self.write(" ANY"),
+            Quantifier::Some => self.write(" SOME"),
+            Quantifier::All => self.write(" ALL"),
+        }
+
+        match right.kind {
+            ExpressionKind::Selection(select) => {
+                self.write(" ");
+                self.visit_nested(|s| s.visit_select(*select))
+            }
+            kind => self.surround_with("(", ")", |s| {
+                s.visit_expression(Expression { kind, alias: None })
+            }),
+        }
+    }
+
     /// A comparison expression
     fn visit_compare(&mut self, compare: Compare<'a>) {
         match compare {
@@ -675,9 +1186,7 @@ pub trait Renderer<'a> {
                     let col = cols.pop().unwrap();
                     let vals = vals.flatten_row().unwrap();
 
-                    self.visit_expression(col);
-                    self.write(" IN ");
-                    self.visit_row(vals)
+                    self.visit_in_list(col, vals, false)
                 }
 
                 // No need to do `IN` if right side is only one value,
@@ -704,6 +1213,14 @@ pub trait Renderer<'a> {
                     },
                 ) => self.visit_multiple_tuple_comparison(row, values, false),
 
+                (
+                    left,
+                    Expression {
+                        kind: ExpressionKind::Row(row),
+                        ..
+                    },
+                ) => self.visit_in_list(left, row, false),
+
                 // expr IN (..)
                 (left, right) => {
                     self.visit_expression(left);
@@ -747,9 +1264,7 @@ pub trait Renderer<'a> {
                     let col = cols.pop().unwrap();
                     let vals = vals.flatten_row().unwrap();
 
-                    self.visit_expression(col);
-                    self.write(" NOT IN ");
-                    self.visit_row(vals)
+                    self.visit_in_list(col, vals, true)
                 }
 
                 // No need to do `IN` if right side is only one value,
@@ -776,6 +1291,14 @@ pub trait Renderer<'a> {
                     },
                 ) => self.visit_multiple_tuple_comparison(row, values, true),
 
+                (
+                    left,
+                    Expression {
+                        kind: ExpressionKind::Row(row),
+                        ..
+                    },
+                ) => self.visit_in_list(left, row, true),
+
                 // expr IN (..)
                 (left, right) => {
                     self.visit_expression(left);
@@ -785,6 +1308,23 @@ pub trait Renderer<'a> {
             },
             Compare::Like(left, right) => self.visit_like(*left, *right),
             Compare::NotLike(left, right) => self.visit_not_like(*left, *right),
+            Compare::ILike(left, right) => self.visit_ilike(*left, *right, false),
+            Compare::NotILike(left, right) => self.visit_ilike(*left, *right, true),
+            Compare::RegexMatch(left, right) => self.visit_regex_match(*left, *right, false, false),
+            Compare::NotRegexMatch(left, right) => {
+                self.visit_regex_match(*left, *right, false, true)
+            }
+            Compare::IRegexMatch(left, right) => self.visit_regex_match(*left, *right, true, false),
+            Compare::NotIRegexMatch(left, right) => {
+                self.visit_regex_match(*left, *right, true, true)
+            }
+            Compare::SimilarTo(left, right) => self.visit_similar_to(*left, *right, false),
+            Compare::NotSimilarTo(left, right) => self.visit_similar_to(*left, *right, true),
+            Compare::StartsWith(left, value) => self.visit_like_escaped(*left, value, false, true),
+            Compare::EndsWith(left, value) => self.visit_like_escaped(*left, value, true, false),
+            Compare::Contains(left, value) => self.visit_like_escaped(*left, value, true, true),
+            Compare::DistinctFrom(left, right) => self.visit_distinct_from(*left, *right, false),
+            Compare::NotDistinctFrom(left, right) => self.visit_distinct_from(*left, *right, true),
             Compare::Null(column) => {
                 self.visit_expression(*column);
                 self.write(" IS NULL")
@@ -807,7 +1347,11 @@ pub trait Renderer<'a> {
                 self.write(" AND ");
                 self.visit_expression(*right)
             }
+            Compare::Quantified(left, operator, quantifier, right) => {
+                self.visit_quantified(*left, operator, quantifier, *right)
+            }
             Compare::Raw(left, comp, right) => {
+                self.register_raw_fragment(&comp);
                 self.visit_expression(*left);
                 self.write(" ");
                 self.write(comp);
@@ -948,6 +1492,73 @@ pub trait Renderer<'a> {
             FunctionType::Concat(concat) => {
                 self.visit_concat(concat);
             }
+            FunctionType::FunctionCall(call) => self.visit_function_call(call),
+            FunctionType::Substring(substring) => self.visit_substring(substring),
+            FunctionType::Trim(trim) => self.visit_trim(trim),
+            FunctionType::Length(length) => self.visit_length(length),
+            FunctionType::Replace(replace) => {
+                self.write("REPLACE");
+                self.surround_with("(", ")", |s| {
+                    s.visit_expression(*replace.expression);
+                    s.write(", ");
+                    s.visit_expression(*replace.from);
+                    s.write(", ");
+                    s.visit_expression(*replace.to);
+                });
+            }
+            FunctionType::Position(position) => self.visit_position(position),
+            FunctionType::Left(left) => self.visit_left(left),
+            FunctionType::Right(right) => self.visit_right(right),
+            FunctionType::Pad(pad) => self.visit_pad(pad),
+            FunctionType::SplitPart(split_part) => self.visit_split_part(split_part),
+            FunctionType::CurrentTimestamp(_) => self.write("CURRENT_TIMESTAMP"),
+            FunctionType::DateTrunc(date_trunc) => self.visit_date_trunc(date_trunc),
+            FunctionType::Extract(extract) => self.visit_extract(extract),
+            FunctionType::AtTimeZone(at_time_zone) => self.visit_at_time_zone(at_time_zone),
+            FunctionType::Abs(abs) => {
+                self.write("ABS");
+                self.surround_with("(", ")", |s| s.visit_expression(*abs.expression));
+            }
+            FunctionType::Round(round) => self.visit_round(round),
+            FunctionType::Ceil(ceil) => {
+                self.write("CEIL");
+                self.surround_with("(", ")", |s| s.visit_expression(*ceil.expression));
+            }
+            FunctionType::Floor(floor) => {
+                self.write("FLOOR");
+                self.surround_with("(", ")", |s| s.visit_expression(*floor.expression));
+            }
+            FunctionType::Power(power) => {
+                self.write("POWER");
+                self.surround_with("(", ")", |s| {
+                    s.visit_expression(*power.base);
+                    s.write(", ");
+                    s.visit_expression(*power.exponent);
+                });
+            }
+            FunctionType::Sqrt(sqrt) => {
+                self.write("SQRT");
+                self.surround_with("(", ")", |s| s.visit_expression(*sqrt.expression));
+            }
+            FunctionType::Modulo(modulo) => {
+                self.write("MOD");
+                self.surround_with("(", ")", |s| {
+                    s.visit_expression(*modulo.dividend);
+                    s.write(", ");
+                    s.visit_expression(*modulo.divisor);
+                });
+            }
+            FunctionType::GreatestLeast(greatest_least) => {
+                self.visit_greatest_least(greatest_least);
+            }
+            FunctionType::NullIf(nullif) => {
+                self.write("NULLIF");
+                self.surround_with("(", ")", |s| {
+                    s.visit_expression(*nullif.left);
+                    s.write(", ");
+                    s.visit_expression(*nullif.right);
+                });
+            }
         };
 
         if let Some(alias) = fun.alias {
@@ -956,6 +1567,42 @@ pub trait Renderer<'a> {
         }
     }
 
+    /// A call to a function by name. Names that are not plain lower-case
+    /// identifiers are quoted.
+    fn visit_function_call(&mut self, call: FunctionCall<'a>) {
+        if let Some(schema) = call.schema {
+            self.visit_function_name(&schema);
+            self.write(".");
+        }
+
+        self.visit_function_name(&call.name);
+
+        self.surround_with("(", ")", |s| {
+            if call.distinct {
+                s.write("DISTINCT ");
+            }
+
+            s.visit_columns(call.arguments);
+        });
+    }
+
+    /// A function or schema name, written as-is if it is a plain identifier
+    /// and not a reserved keyword, and quoted otherwise. Plain names keep
+    /// resolving case-insensitively.
+    fn visit_function_name(&mut self, name: &str) {
+        let plain = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && RESERVED_KEYWORDS
+                .binary_search(&name.to_ascii_lowercase().as_str())
+                .is_err();
+
+        if plain {
+            self.write(name);
+        } else {
+            self.surround_with_backticks(name);
+        }
+    }
+
     fn visit_concat(&mut self, concat: Concat<'a>) {
         let len = concat.exprs.len();
 
@@ -971,6 +1618,196 @@ pub trait Renderer<'a> {
         });
     }
 
+    /// An integer argument of a string function, such as a position or a
+    /// length.
+    fn visit_integer_argument(&mut self, expression: Expression<'a>) {
+        self.visit_expression(expression);
+    }
+
+    /// The standard `SUBSTRING(string FROM start FOR length)`.
+    fn visit_substring(&mut self, substring: Substring<'a>) {
+        self.write("SUBSTRING");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*substring.expression);
+            s.write(" FROM ");
+            s.visit_integer_argument(*substring.start);
+
+            if let Some(length) = substring.length {
+                s.write(" FOR ");
+                s.visit_integer_argument(*length);
+            }
+        });
+    }
+
+    /// The standard `TRIM(LEADING chars FROM string)`, leaving out the side
+    /// and the characters when trimming whitespace from both sides.
+    fn visit_trim(&mut self, trim: Trim<'a>) {
+        self.write("TRIM");
+        self.surround_with("(", ")", |s| {
+            match (trim.side, trim.characters) {
+                (TrimSide::Both, None) => (),
+                (side, characters) => {
+                    s.write(match side {
+                        TrimSide::Leading => "LEADING ",
+                        TrimSide::Trailing => "TRAILING ",
+                        TrimSide::Both => "BOTH ",
+                    });
+
+                    if let Some(characters) = characters {
+                        s.visit_expression(*characters);
+                        s.write(" ");
+                    }
+
+                    s.write("FROM ");
+                }
+            }
+
+            s.visit_expression(*trim.expression);
+        });
+    }
+
+    /// The number of characters in a string, with the standard
+    /// `CHAR_LENGTH`.
+    fn visit_length(&mut self, length: Length<'a>) {
+        self.write("CHAR_LENGTH");
+        self.surround_with("(", ")", |s| s.visit_expression(*length.expression));
+    }
+
+    /// The first characters of a string, `LEFT(string, count)`.
+    fn visit_left(&mut self, left: Left<'a>) {
+        self.write("LEFT");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*left.expression);
+            s.write(", ");
+            s.visit_integer_argument(*left.count);
+        });
+    }
+
+    /// The last characters of a string, `RIGHT(string, count)`.
+    fn visit_right(&mut self, right: Right<'a>) {
+        self.write("RIGHT");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*right.expression);
+            s.write(", ");
+            s.visit_integer_argument(*right.count);
+        });
+    }
+
+    /// A string padded to a length, `LPAD(string, length, fill)` or `RPAD`.
+    fn visit_pad(&mut self, pad: Pad<'a>) {
+        self.write(if pad.left { "LPAD" } else { "RPAD" });
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*pad.expression);
+            s.write(", ");
+            s.visit_integer_argument(*pad.length);
+            s.write(", ");
+            s.visit_expression(*pad.fill);
+        });
+    }
+
+    /// The standard `POSITION(substring IN string)`.
+    fn visit_position(&mut self, position: Position<'a>) {
+        self.write("POSITION");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*position.substring);
+            s.write(" IN ");
+            s.visit_expression(*position.expression);
+        });
+    }
+
+    /// `SPLIT_PART(string, delimiter, index)`, which is not part of the SQL
+    /// standard.
+    fn visit_split_part(&mut self, split_part: SplitPart<'a>) {
+        self.write("SPLIT_PART");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*split_part.expression);
+            s.write(", ");
+            s.visit_expression(*split_part.delimiter);
+            s.write(", ");
+            s.visit_integer_argument(*split_part.index);
+        });
+    }
+
+    /// The standard interval literal, `INTERVAL '7' DAY`. Weeks are written
+    /// as days and quarters as months, which the standard has no field for.
+    fn visit_interval(&mut self, interval: Interval) {
+        // Widened, so that large quantities do not overflow.
+        let quantity = i128::from(interval.quantity);
+
+        let (quantity, field) = match interval.field {
+            DateTimeField::Week => (quantity * 7, DateTimeField::Day),
+            DateTimeField::Quarter => (quantity * 3, DateTimeField::Month),
+            field => (quantity, field),
+        };
+
+        self.write(format!("INTERVAL '{quantity}' {}", field.as_str()));
+    }
+
+    /// A timestamp plus or minus an interval, `(x + INTERVAL '7' DAY)`.
+    fn visit_interval_arithmetic(
+        &mut self,
+        left: Expression<'a>,
+        interval: Interval,
+        subtract: bool,
+    ) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(if subtract { " - " } else { " + " });
+            s.visit_interval(interval);
+        });
+    }
+
+    /// `DATE_TRUNC('week', timestamp)`, which is not part of the SQL standard.
+    fn visit_date_trunc(&mut self, date_trunc: DateTrunc<'a>) {
+        self.write("DATE_TRUNC");
+        self.surround_with("(", ")", |s| {
+            s.write(format!("'{}', ", date_trunc.field.as_str().to_lowercase()));
+            s.visit_expression(*date_trunc.expression);
+        });
+    }
+
+    /// The standard `EXTRACT(YEAR FROM timestamp)`.
+    fn visit_extract(&mut self, extract: Extract<'a>) {
+        self.write("EXTRACT");
+        self.surround_with("(", ")", |s| {
+            s.write(extract.field.as_str());
+            s.write(" FROM ");
+            s.visit_expression(*extract.expression);
+        });
+    }
+
+    /// The standard `(timestamp AT TIME ZONE zone)`.
+    fn visit_at_time_zone(&mut self, at_time_zone: AtTimeZone<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*at_time_zone.expression);
+            s.write(" AT TIME ZONE ");
+            s.visit_expression(*at_time_zone.time_zone);
+        });
+    }
+
+    fn visit_round(&mut self, round: Round<'a>) {
+        self.write("ROUND");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*round.expression);
+
+            if let Some(digits) = round.digits {
+                s.write(", ");
+                s.visit_integer_argument(*digits);
+            }
+        });
+    }
+
+    /// The largest or the smallest of the values, with `GREATEST` or `LEAST`.
+    fn visit_greatest_least(&mut self, greatest_least: GreatestLeast<'a>) {
+        self.write(if greatest_least.greatest {
+            "GREATEST"
+        } else {
+            "LEAST"
+        });
+
+        self.surround_with("(", ")", |s| s.visit_columns(greatest_least.exprs));
+    }
+
     fn visit_partitioning(&mut self, over: Over<'a>) {
         if !over.partitioning.is_empty() {
             let len = over.partitioning.len();
@@ -995,3 +1832,321 @@ pub trait Renderer<'a> {
         }
     }
 }
+
+/// True if the expression is not resolved against the columns of the given
+/// tables: parameters, and columns qualified with another table.
+fn is_outer_reference(expression: &Expression<'_>, tables: &[Table<'_>]) -> bool {
+    match &expression.kind {
+        ExpressionKind::Parameterized(_) | ExpressionKind::Placeholder(_) => true,
+        ExpressionKind::Column(column) => match column.table {
+            Some(ref table) => !tables.contains(table),
+            None => false,
+        },
+        ExpressionKind::Row(row) => row
+            .values
+            .iter()
+            .all(|value| is_outer_reference(value, tables)),
+        ExpressionKind::Value(value) => is_outer_reference(value, tables),
+        _ => false,
+    }
+}
+
+/// Percent-encodes everything except the unreserved URL characters.
+fn url_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    /// A renderer using only the default implementations of the trait,
+    /// writing `?` for parameters.
+    #[derive(Default)]
+    struct Generic {
+        query: String,
+        parameters: Vec<SqlValue>,
+    }
+
+    impl<'a> Renderer<'a> for Generic {
+        const C_BACKTICK_OPEN: &'static str = "`";
+        const C_BACKTICK_CLOSE: &'static str = "`";
+        const C_WILDCARD: &'static str = "%";
+
+        fn build<Q>(query: Q) -> (String, Vec<SqlValue>)
+        where
+            Q: Into<Query<'a>>,
+        {
+            let mut renderer = Generic::default();
+            renderer.visit_query(query.into());
+
+            (renderer.query, renderer.parameters)
+        }
+
+        fn write<D: fmt::Display>(&mut self, s: D) {
+            write!(&mut self.query, "{s}").unwrap();
+        }
+
+        fn add_parameter(&mut self, value: SqlValue) {
+            self.parameters.push(value);
+        }
+
+        fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+            if let Some(limit) = limit {
+                self.write_clause("LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)));
+            }
+
+            if let Some(offset) = offset {
+                self.write_clause("OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)));
+            }
+        }
+
+        fn visit_ordering(&mut self, ordering: Ordering<'a>) {
+            let len = ordering.0.len();
+
+            for (i, (value, _)) in ordering.0.into_iter().enumerate() {
+                self.visit_expression(value);
+
+                if i < (len - 1) {
+                    self.write(", ");
+                }
+            }
+        }
+
+        fn visit_insert(&mut self, _: Insert<'a>) {
+            unimplemented!()
+        }
+
+        fn parameter_substitution(&mut self) {
+            self.write("?");
+        }
+
+        fn visit_aggregate_to_string(&mut self, _: Expression<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_json_extract(&mut self, _: JsonExtract<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_json_extract_last_array_item(&mut self, _: JsonExtractLastArrayElem<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_json_extract_first_array_item(&mut self, _: JsonExtractFirstArrayElem<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_array_contains(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_array_contained(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_array_overlaps(&mut self, _: Expression<'a>, _: Expression<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_json_type_equals(&mut self, _: Expression<'a>, _: JsonType<'a>, _: bool) {
+            unimplemented!()
+        }
+
+        #[cfg(any(feature = "postgresql", feature = "mysql"))]
+        fn visit_json_unquote(&mut self, _: JsonUnquote<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "postgresql")]
+        fn visit_to_jsonb(&mut self, _: ToJsonb<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "postgresql")]
+        fn visit_json_build_object(&mut self, _: JsonBuildObject<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "postgresql")]
+        fn visit_json_agg(&mut self, _: JsonAgg<'a>) {
+            unimplemented!()
+        }
+
+        #[cfg(feature = "postgresql")]
+        fn visit_encode(&mut self, _: Encode<'a>) {
+            unimplemented!()
+        }
+
+        fn visit_delete(&mut self, _: Delete<'a>) {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_escaped_pattern_comparison() {
+        let mut query = Select::from_table("users");
+        query.so_that("discount".has_substring(r"50%_!\"));
+
+        let (sql, params) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `discount` LIKE ? ESCAPE '!'",
+            sql
+        );
+        assert_eq!(vec![SqlValue::Text(r"%50!%!_!!\%".into())], params);
+    }
+
+    #[test]
+    fn test_regex_match() {
+        let mut query = Select::from_table("users");
+        query.so_that("name".regex_match("^a"));
+        let (sql, params) = Generic::build(query);
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `name` REGEXP ?", sql);
+        assert_eq!(vec![SqlValue::Text("^a".into())], params);
+
+        let mut query = Select::from_table("users");
+        query.so_that("name".not_iregex_match("^a"));
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE LOWER(`name`) NOT REGEXP LOWER(?)",
+            sql
+        );
+    }
+
+    fn scores(min: i64) -> Select<'static> {
+        let mut select = Select::from_table("scores");
+        select.column("score");
+        select.so_that("score".greater_than(min));
+        select
+    }
+
+    #[test]
+    fn test_quantified_comparison_rewritten_to_exists() {
+        let mut query = Select::from_table("users");
+        query.so_that(("users", "score").quantified(
+            ComparisonOperator::GreaterThan,
+            Quantifier::Any,
+            scores(10),
+        ));
+
+        let (sql, params) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE EXISTS (SELECT `scores`.* FROM `scores` WHERE (`score` > ? AND `users`.`score` > `score`))",
+            sql
+        );
+        assert_eq!(vec![SqlValue::Int64(10)], params);
+    }
+
+    #[test]
+    fn test_quantified_all_comparison_rejects_nulls() {
+        let mut query = Select::from_table("users");
+        query.so_that(("users", "score").quantified(
+            ComparisonOperator::GreaterThan,
+            Quantifier::All,
+            scores(10),
+        ));
+
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE NOT EXISTS (SELECT `scores`.* FROM `scores` WHERE (`score` > ? AND ((NOT `users`.`score` > `score`) OR `users`.`score` IS NULL OR `score` IS NULL)))",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_quantified_comparison_drops_the_column_alias() {
+        let mut scores = Select::from_table("scores");
+        scores.value(Column::from("score").alias("points"));
+
+        let mut query = Select::from_table("users");
+        query.so_that(("users", "score").quantified(
+            ComparisonOperator::Equals,
+            Quantifier::Any,
+            scores,
+        ));
+
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE EXISTS (SELECT `scores`.* FROM `scores` WHERE `users`.`score` = `score`)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_quantified_comparison_over_aggregate_uses_derived_table() {
+        let mut scores = Select::from_table("scores");
+        scores.value(max("score").alias("best"));
+
+        let mut query = Select::from_table("users");
+        query.so_that(("users", "score").quantified(
+            ComparisonOperator::GreaterThanOrEquals,
+            Quantifier::All,
+            scores,
+        ));
+
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE NOT EXISTS (SELECT `quantified`.* FROM (SELECT MAX(`score`) AS `value` FROM `scores`) AS `quantified` WHERE ((NOT `users`.`score` >= `quantified`.`value`) OR `users`.`score` IS NULL OR `quantified`.`value` IS NULL))",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_quantified_comparison_not_rewritten_for_inner_columns() {
+        let mut query = Select::from_table("users");
+        query.so_that("score".quantified(
+            ComparisonOperator::GreaterThan,
+            Quantifier::All,
+            scores(10),
+        ));
+
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `score` > ALL (SELECT `score` FROM `scores` WHERE `score` > ?)",
+            sql
+        );
+
+        let mut query = Select::from_table("scores");
+        query.so_that(("scores", "score").quantified(
+            ComparisonOperator::GreaterThan,
+            Quantifier::Any,
+            scores(10),
+        ));
+
+        let (sql, _) = Generic::build(query);
+
+        assert_eq!(
+            "SELECT `scores`.* FROM `scores` WHERE `scores`.`score` > ANY (SELECT `score` FROM `scores` WHERE `score` > ?)",
+            sql
+        );
+    }
+}
@@ -0,0 +1,720 @@
+use crate::{ast::*, renderer::Renderer};
+use std::fmt::{self, Write};
+
+/// A visitor to generate queries for the MySQL database.
+///
+/// Parameters are substituted with `?`, and the returned values should be
+/// bound in the same order.
+#[cfg_attr(feature = "docs", doc(cfg(feature = "mysql")))]
+pub struct Mysql {
+    query: String,
+    parameters: Vec<SqlValue>,
+}
+
+impl<'a> Renderer<'a> for Mysql {
+    const C_BACKTICK_OPEN: &'static str = "`";
+    const C_BACKTICK_CLOSE: &'static str = "`";
+    const C_WILDCARD: &'static str = "%";
+    const MAX_PARAMETERS: usize = 65535;
+
+    fn build<Q>(query: Q) -> (String, Vec<SqlValue>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mut mysql = Mysql {
+            query: String::with_capacity(4096),
+            parameters: Vec::with_capacity(128),
+        };
+
+        Mysql::visit_query(&mut mysql, query.into());
+
+        (mysql.query, mysql.parameters)
+    }
+
+    fn write<D: fmt::Display>(&mut self, s: D) {
+        write!(&mut self.query, "{s}")
+            .expect("we ran out of memory or something else why write failed");
+    }
+
+    fn add_parameter(&mut self, value: SqlValue) {
+        self.parameters.push(value);
+    }
+
+    fn parameter_substitution(&mut self) {
+        self.write("?");
+    }
+
+    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => {
+                self.write(" LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)));
+
+                self.write(" OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (None, Some(offset)) => {
+                // MySQL has no `OFFSET` without a `LIMIT`, so the limit is
+                // the largest row count it accepts.
+                self.write(" LIMIT 18446744073709551615");
+
+                self.write(" OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (Some(limit), None) => {
+                self.write(" LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)))
+            }
+            (None, None) => (),
+        }
+    }
+
+    /// MySQL has no `NULLS FIRST` or `NULLS LAST`. `NULL` sorts before any
+    /// other value, so the other orders sort by `x IS NULL` first.
+    fn visit_ordering(&mut self, ordering: Ordering<'a>) {
+        let len = ordering.0.len();
+
+        for (i, (value, ordering)) in ordering.0.into_iter().enumerate() {
+            match ordering {
+                Some(Order::AscNullsLast) => {
+                    self.visit_expression(value.clone());
+                    self.write(" IS NULL, ");
+                }
+                Some(Order::DescNullsFirst) => {
+                    self.visit_expression(value.clone());
+                    self.write(" IS NULL DESC, ");
+                }
+                _ => (),
+            }
+
+            let direction = ordering.map(|dir| match dir {
+                Order::Asc | Order::AscNullsFirst | Order::AscNullsLast => " ASC",
+                Order::Desc | Order::DescNullsFirst | Order::DescNullsLast => " DESC",
+            });
+
+            self.visit_expression(value);
+            self.write(direction.unwrap_or(""));
+
+            if i < (len - 1) {
+                self.write(", ");
+            }
+        }
+    }
+
+    /// Conflicts are handled with `INSERT IGNORE` and `ON DUPLICATE KEY
+    /// UPDATE`, which apply to every unique key of the table, so the
+    /// constraint columns are not written.
+    ///
+    /// # Panics
+    ///
+    /// If the update on conflict has conditions, which MySQL cannot express.
+    fn visit_insert(&mut self, insert: Insert<'a>) {
+        self.write("INSERT ");
+
+        if let Some(OnConflict::DoNothing) = insert.on_conflict {
+            self.write("IGNORE ");
+        }
+
+        if let Some(table) = insert.table.clone() {
+            self.write("INTO ");
+            self.visit_table(table, true);
+        }
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => {
+                if row.values.is_empty() {
+                    self.write(" () VALUES ()");
+                } else {
+                    self.columns_to_bracket_list(insert.columns);
+                    self.write(" VALUES ");
+                    self.visit_row(row);
+                }
+            }
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                self.columns_to_bracket_list(insert.columns);
+                self.write(" VALUES ");
+                let values_len = values.len();
+
+                for (i, row) in values.into_iter().enumerate() {
+                    self.visit_row(row);
+
+                    if i < (values_len - 1) {
+                        self.write(", ");
+                    }
+                }
+            }
+            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr)),
+        }
+
+        if let Some(OnConflict::Update(update, _)) = insert.on_conflict {
+            assert!(
+                update.conditions.is_none(),
+                "Conditional updates on conflict are not supported on MySQL"
+            );
+
+            self.write(" ON DUPLICATE KEY UPDATE ");
+            self.visit_update_set(update);
+        }
+
+        if let Some(returning) = insert.returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write(" RETURNING ");
+                self.visit_columns(values);
+            }
+        };
+
+        self.visit_comment(insert.comment);
+    }
+
+    fn visit_delete(&mut self, delete: Delete<'a>) {
+        self.write("DELETE FROM ");
+        self.visit_table(delete.table, true);
+
+        if let Some(conditions) = delete.conditions {
+            self.write(" WHERE ");
+            self.visit_conditions(conditions);
+        }
+
+        if let Some(returning) = delete.returning {
+            self.write(" RETURNING ");
+            self.visit_columns(returning);
+        }
+
+        self.visit_comment(delete.comment);
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'a>) {
+        self.write("GROUP_CONCAT");
+        self.surround_with("(", ")", |s| s.visit_expression(value));
+    }
+
+    fn visit_similar_to(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+        panic!("SIMILAR TO is not supported on MySQL")
+    }
+
+    /// The null-safe equality operator `<=>`, negated with `NOT` for
+    /// `IS DISTINCT FROM`.
+    fn visit_distinct_from(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        if not {
+            self.visit_expression(left);
+            self.write(" <=> ");
+            self.visit_expression(right);
+        } else {
+            self.write("NOT ");
+            self.surround_with("(", ")", |s| {
+                s.visit_expression(left);
+                s.write(" <=> ");
+                s.visit_expression(right);
+            });
+        }
+    }
+
+    /// MySQL has no `SPLIT_PART`, so the part is cut out with two nested
+    /// `SUBSTRING_INDEX` calls. Unlike `SPLIT_PART`, an index past the last
+    /// part returns the last part instead of an empty string.
+    fn visit_split_part(&mut self, split_part: SplitPart<'a>) {
+        let delimiter = *split_part.delimiter;
+
+        self.write("SUBSTRING_INDEX");
+        self.surround_with("(", ")", |s| {
+            s.write("SUBSTRING_INDEX");
+            s.surround_with("(", ")", |s| {
+                s.visit_expression(*split_part.expression);
+                s.write(", ");
+                s.visit_expression(delimiter.clone());
+                s.write(", ");
+                s.visit_integer_argument(*split_part.index);
+            });
+
+            s.write(", ");
+            s.visit_expression(delimiter);
+            s.write(", -1");
+        });
+    }
+
+    /// `INTERVAL 7 DAY`, with the quantity unquoted.
+    fn visit_interval(&mut self, interval: Interval) {
+        self.write(format!(
+            "INTERVAL {} {}",
+            interval.quantity,
+            interval.field.as_str()
+        ));
+    }
+
+    /// `DATE_ADD(timestamp, INTERVAL 7 DAY)` or `DATE_SUB`.
+    fn visit_interval_arithmetic(
+        &mut self,
+        left: Expression<'a>,
+        interval: Interval,
+        subtract: bool,
+    ) {
+        self.write(if subtract { "DATE_SUB" } else { "DATE_ADD" });
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(", ");
+            s.visit_interval(interval);
+        });
+    }
+
+    /// MySQL has no `DATE_TRUNC`. The timestamp is formatted with the fields
+    /// below the truncated one zeroed, or computed from the weekday or the
+    /// quarter, and converted back to a `DATETIME`.
+    fn visit_date_trunc(&mut self, date_trunc: DateTrunc<'a>) {
+        let expression = *date_trunc.expression;
+
+        self.write("CAST");
+        self.surround_with("(", ")", |s| {
+            let format = match date_trunc.field {
+                DateTimeField::Second => "%Y-%m-%d %H:%i:%s",
+                DateTimeField::Minute => "%Y-%m-%d %H:%i:00",
+                DateTimeField::Hour => "%Y-%m-%d %H:00:00",
+                DateTimeField::Day => "%Y-%m-%d 00:00:00",
+                DateTimeField::Month => "%Y-%m-01 00:00:00",
+                DateTimeField::Year => "%Y-01-01 00:00:00",
+                DateTimeField::Week => {
+                    s.write("DATE_SUB(DATE(");
+                    s.visit_expression(expression.clone());
+                    s.write("), INTERVAL WEEKDAY(");
+                    s.visit_expression(expression);
+                    s.write(") DAY) AS DATETIME");
+
+                    return;
+                }
+                DateTimeField::Quarter => {
+                    s.write("MAKEDATE(YEAR(");
+                    s.visit_expression(expression.clone());
+                    s.write("), 1) + INTERVAL (QUARTER(");
+                    s.visit_expression(expression);
+                    s.write(") - 1) QUARTER AS DATETIME");
+
+                    return;
+                }
+            };
+
+            s.write("DATE_FORMAT(");
+            s.visit_expression(expression);
+            s.write(format!(", '{format}') AS DATETIME"));
+        });
+    }
+
+    /// `CONVERT_TZ` from the session time zone, in which MySQL returns
+    /// timestamps.
+    fn visit_at_time_zone(&mut self, at_time_zone: AtTimeZone<'a>) {
+        self.write("CONVERT_TZ");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*at_time_zone.expression);
+            s.write(", @@session.time_zone, ");
+            s.visit_expression(*at_time_zone.time_zone);
+        });
+    }
+
+    fn visit_json_extract(&mut self, json_extract: JsonExtract<'a>) {
+        if json_extract.extract_as_string {
+            self.write("JSON_UNQUOTE(");
+        }
+
+        self.write("JSON_EXTRACT(");
+        self.visit_expression(*json_extract.column);
+        self.write(", ");
+
+        match json_extract.path {
+            JsonPath::String(path) => self.visit_parameterized(SqlValue::Text(path.into_owned())),
+            #[cfg(feature = "postgresql")]
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for MySQL"),
+        }
+
+        self.write(")");
+
+        if json_extract.extract_as_string {
+            self.write(")");
+        }
+    }
+
+    fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) {
+        self.write("JSON_EXTRACT(");
+        self.visit_expression(*extract.expr.clone());
+        self.write(", CONCAT('$[', JSON_LENGTH(");
+        self.visit_expression(*extract.expr);
+        self.write(") - 1, ']'))");
+    }
+
+    fn visit_json_extract_first_array_item(&mut self, extract: JsonExtractFirstArrayElem<'a>) {
+        self.write("JSON_EXTRACT(");
+        self.visit_expression(*extract.expr);
+        self.write(", '$[0]')");
+    }
+
+    fn visit_array_contains(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        if not {
+            self.write("NOT ");
+        }
+
+        self.write("JSON_CONTAINS(");
+        self.visit_expression(left);
+        self.write(", ");
+        self.visit_expression(right);
+        self.write(")");
+    }
+
+    fn visit_array_contained(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        if not {
+            self.write("NOT ");
+        }
+
+        self.write("JSON_CONTAINS(");
+        self.visit_expression(right);
+        self.write(", ");
+        self.visit_expression(left);
+        self.write(")");
+    }
+
+    fn visit_array_overlaps(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.write("JSON_OVERLAPS(");
+        self.visit_expression(left);
+        self.write(", ");
+        self.visit_expression(right);
+        self.write(")");
+    }
+
+    fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) {
+        let operator = if not { " != " } else { " = " };
+
+        self.write("(JSON_TYPE(");
+        self.visit_expression(left.clone());
+        self.write(")");
+        self.write(operator);
+
+        match json_type {
+            JsonType::Array => self.write("'ARRAY'"),
+            JsonType::Boolean => self.write("'BOOLEAN'"),
+            JsonType::Number => {
+                self.write("'INTEGER'");
+                self.write(if not { " AND " } else { " OR " });
+                self.write("JSON_TYPE(");
+                self.visit_expression(left);
+                self.write(")");
+                self.write(operator);
+                self.write("'DOUBLE'");
+            }
+            JsonType::Object => self.write("'OBJECT'"),
+            JsonType::String => self.write("'STRING'"),
+            JsonType::Null => self.write("'NULL'"),
+            JsonType::ColumnRef(column) => {
+                self.write("JSON_TYPE(");
+                self.visit_column(*column);
+                self.write(")");
+            }
+        }
+
+        self.write(")");
+    }
+
+    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) {
+        self.write("JSON_UNQUOTE");
+        self.surround_with("(", ")", |s| s.visit_expression(*json_unquote.expr));
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_to_jsonb(&mut self, _: ToJsonb<'a>) {
+        panic!("to_jsonb is not supported on MySQL")
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'a>) {
+        let len = json_build_object.values.len();
+
+        self.write("JSON_OBJECT");
+        self.surround_with("(", ")", |s| {
+            for (i, (name, value)) in json_build_object.values.into_iter().enumerate() {
+                s.visit_parameterized(SqlValue::Text(name.into_owned()));
+                s.write(", ");
+                s.visit_expression(value);
+
+                if i < (len - 1) {
+                    s.write(", ");
+                }
+            }
+        });
+    }
+
+    /// # Panics
+    ///
+    /// If the aggregate is distinct or ordered, which `JSON_ARRAYAGG` does not
+    /// support.
+    #[cfg(feature = "postgresql")]
+    fn visit_json_agg(&mut self, json_agg: JsonAgg<'a>) {
+        assert!(
+            !json_agg.distinct && json_agg.order_by.is_none(),
+            "Distinct or ordered JSON aggregates are not supported on MySQL"
+        );
+
+        self.write("JSON_ARRAYAGG");
+        self.surround_with("(", ")", |s| s.visit_expression(json_agg.expression));
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_encode(&mut self, encode: Encode<'a>) {
+        match encode.format {
+            EncodeFormat::Base64 => self.write("TO_BASE64"),
+            EncodeFormat::Hex => self.write("HEX"),
+            EncodeFormat::Escape => panic!("The escape encoding is not supported on MySQL"),
+        }
+
+        self.surround_with("(", ")", |s| s.visit_expression(encode.expression));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::renderer::*;
+
+    #[test]
+    fn test_identifiers_and_parameters() {
+        let mut select = Select::from_table("users");
+        select.so_that("name".equals("Musti").and("age".greater_than(3)));
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE (`name` = ? AND `age` > ?)",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti"), Value::from(3)], params);
+    }
+
+    #[test]
+    fn test_offset_without_limit() {
+        let mut select = Select::from_table("users");
+        select.offset(10);
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` LIMIT 18446744073709551615 OFFSET ?",
+            sql
+        );
+        assert_eq!(vec![Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_nulls_last_ordering() {
+        let mut select = Select::from_table("users");
+        select.order_by("name".ascend_nulls_last());
+        select.order_by("age".descend_nulls_first());
+        select.order_by("id".descend_nulls_last());
+
+        let (sql, _) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` ORDER BY `name` IS NULL, `name` ASC, `age` IS NULL DESC, `age` DESC, `id` DESC",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_insert_on_duplicate_key() {
+        let mut insert = Insert::single_into("users");
+        insert.value("id", 1);
+        insert.value("name", "Musti");
+
+        let mut update = Update::table("users");
+        update.set("name", "Musti");
+
+        let mut insert = insert.build();
+        insert.on_conflict(OnConflict::Update(update, vec![Column::from("id")]));
+
+        let (sql, params) = Mysql::build(insert);
+
+        assert_eq!(
+            "INSERT INTO `users` (`id`,`name`) VALUES (?,?) ON DUPLICATE KEY UPDATE `name` = ?",
+            sql
+        );
+        assert_eq!(3, params.len());
+    }
+
+    #[test]
+    fn test_insert_ignore() {
+        let mut insert = Insert::single_into("users");
+        insert.value("id", 1);
+
+        let mut insert = insert.build();
+        insert.on_conflict(OnConflict::DoNothing);
+
+        let (sql, _) = Mysql::build(insert);
+
+        assert_eq!("INSERT IGNORE INTO `users` (`id`) VALUES (?)", sql);
+    }
+
+    #[test]
+    fn test_case_insensitive_and_regex_matching() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Column::from("name")
+                .ilike("%musti%")
+                .and(Column::from("email").not_regex_match("^b"))
+                .and(Column::from("email").iregex_match("^c")),
+        );
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE (LOWER(`name`) LIKE LOWER(?) AND `email` NOT REGEXP ? AND LOWER(`email`) REGEXP LOWER(?))",
+            sql
+        );
+        assert_eq!(3, params.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "SIMILAR TO is not supported on MySQL")]
+    fn test_similar_to_is_not_supported() {
+        let mut select = Select::from_table("users");
+        select.so_that(Column::from("code").similar_to("(a|b)%"));
+
+        Mysql::build(select);
+    }
+
+    #[test]
+    fn test_quantified_comparison_uses_exists() {
+        let mut scores = Select::from_table("scores");
+        scores.column("score");
+
+        let mut select = Select::from_table("users");
+        select.so_that(("users", "score").quantified(
+            ComparisonOperator::GreaterThan,
+            Quantifier::All,
+            scores,
+        ));
+
+        let (sql, _) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE NOT EXISTS (SELECT `scores`.* FROM `scores` WHERE ((NOT `users`.`score` > `score`) OR `users`.`score` IS NULL OR `score` IS NULL))",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_escaped_pattern_comparison() {
+        let mut select = Select::from_table("users");
+        select.so_that("discount".has_prefix(r"50%\"));
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `discount` LIKE ? ESCAPE '!'",
+            sql
+        );
+        assert_eq!(vec![Value::from(r"50!%\%")], params);
+    }
+
+    #[test]
+    fn test_distinct_from() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".is_distinct_from(1)
+                .and("b".is_not_distinct_from(Column::from("c"))),
+        );
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE (NOT (`a` <=> ?) AND `b` <=> `c`)",
+            sql
+        );
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_string_functions() {
+        let mut select = Select::from_table("users");
+        select.value(substring(
+            Column::from("name"),
+            2,
+            Some(Expression::from(3)),
+        ));
+        select.value(ltrim(Column::from("name")));
+        select.value(length(Column::from("name")));
+        select.value(position("@", Column::from("email")));
+        select.value(split_part(Column::from("email"), "@", 2));
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT SUBSTRING(`name` FROM ? FOR ?), TRIM(LEADING FROM `name`), CHAR_LENGTH(`name`), POSITION(? IN `email`), SUBSTRING_INDEX(SUBSTRING_INDEX(`email`, ?, ?), ?, -1) FROM `users`",
+            sql
+        );
+        assert_eq!(
+            vec![
+                Value::from(2),
+                Value::from(3),
+                Value::from("@"),
+                Value::from("@"),
+                Value::from(2),
+                Value::from("@"),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn test_date_time_functions() {
+        let mut select = Select::from_table("posts");
+        select.value(date_trunc(DateTimeField::Month, Column::from("created_at")));
+        select.value(date_trunc(DateTimeField::Week, Column::from("created_at")));
+        select.value(extract(DateTimeField::Quarter, Column::from("created_at")));
+        select.value(at_time_zone(Column::from("created_at"), "Europe/Helsinki"));
+        select.so_that(
+            "created_at".greater_than(Expression::from(now()) - interval(7, DateTimeField::Day)),
+        );
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT CAST(DATE_FORMAT(`created_at`, '%Y-%m-01 00:00:00') AS DATETIME), CAST(DATE_SUB(DATE(`created_at`), INTERVAL WEEKDAY(`created_at`) DAY) AS DATETIME), EXTRACT(QUARTER FROM `created_at`), CONVERT_TZ(`created_at`, @@session.time_zone, ?) FROM `posts` WHERE `created_at` > DATE_SUB(CURRENT_TIMESTAMP, INTERVAL 7 DAY)",
+            sql
+        );
+        assert_eq!(vec![Value::from("Europe/Helsinki")], params);
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let flags = || Expression::from(Column::from("flags"));
+
+        let mut select = Select::from_table("users");
+        select.value(flags() ^ Expression::from(1));
+        select.so_that((flags() & Expression::from(4)).not_equals(0));
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT (`flags` ^ ?) FROM `users` WHERE (`flags` & ?) <> ?",
+            sql
+        );
+        assert_eq!(3, params.len());
+    }
+
+    #[test]
+    fn test_json_extract() {
+        let mut select = Select::from_table("users");
+        select.value(json_extract(
+            Column::from("data"),
+            JsonPath::string("$.name"),
+            true,
+        ));
+
+        let (sql, params) = Mysql::build(select);
+
+        assert_eq!(
+            "SELECT JSON_UNQUOTE(JSON_EXTRACT(`data`, ?)) FROM `users`",
+            sql
+        );
+        assert_eq!(vec![Value::from("$.name")], params);
+    }
+}
@@ -1,830 +1,3288 @@
-use serde_json::Value;
-
-use crate::{ast::*, renderer::Renderer};
-use std::fmt::{self, Write};
+use crate::{
+    ast::*,
+    renderer::{Renderer, Template},
+};
+use anyhow::anyhow;
+use std::{
+    borrow::Cow,
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt,
+    hash::{Hash, Hasher},
+    io, mem,
+};
+
+/// The number of key-value pairs fitting in a single `json_build_object` call,
+/// which is limited to 100 arguments.
+const JSON_BUILD_OBJECT_MAX_PAIRS: usize = 50;
 
 /// A visitor to generate queries for the PostgreSQL database.
 ///
-/// The returned parameter values implement the `ToSql` trait from postgres and
-/// can be used directly with the database.
+/// The returned parameter values are typed, see [SqlValue](../ast/enum.SqlValue.html)
+/// for how they should be bound to the query.
 #[cfg_attr(feature = "docs", doc(cfg(feature = "postgresql")))]
-pub struct Postgres {
-    query: String,
-    parameters: Vec<Value>,
-}
+pub type Postgres = PostgresRenderer<String>;
 
-impl<'a> Renderer<'a> for Postgres {
-    const C_BACKTICK_OPEN: &'static str = "\"";
-    const C_BACKTICK_CLOSE: &'static str = "\"";
-    const C_WILDCARD: &'static str = "%";
+/// The PostgreSQL visitor, writing the query into `W` as it is rendered. Use
+/// [Postgres](type.Postgres.html), which renders into a `String`, and its
+/// [render_to_writer](type.Postgres.html#method.render_to_writer) or
+/// [render_to_fmt](type.Postgres.html#method.render_to_fmt) methods to
+/// stream the query into another sink.
+#[cfg_attr(feature = "docs", doc(cfg(feature = "postgresql")))]
+pub struct PostgresRenderer<W> {
+    query: W,
+    parameters: Vec<SqlValue>,
+    parameter_types: Vec<Option<SqlType>>,
+    parameter_positions: HashMap<u64, Vec<usize>>,
+    placeholders: HashMap<String, usize>,
+    raw_fragments: Vec<String>,
+    inline_parameters: bool,
+    normalize: bool,
+    pretty: bool,
+    indentation: usize,
+    in_lists_as_arrays: bool,
+    deduplicate_parameters: bool,
+    strict: bool,
+}
 
-    fn build<Q>(query: Q) -> (String, Vec<Value>)
-    where
-        Q: Into<Query<'a>>,
-    {
-        let mut postgres = Postgres {
-            query: String::with_capacity(4096),
-            parameters: Vec::with_capacity(128),
-        };
+impl Default for Postgres {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        Postgres::visit_query(&mut postgres, query.into());
+impl Postgres {
+    /// Creates a renderer with the default settings. Use the setters to change
+    /// how queries are rendered, and [render](#method.render) to render a query.
+    /// `Postgres::build` renders with the default settings.
+    pub fn new() -> Self {
+        Postgres {
+            query: String::new(),
+            parameters: Vec::new(),
+            parameter_types: Vec::new(),
+            parameter_positions: HashMap::new(),
+            placeholders: HashMap::new(),
+            raw_fragments: Vec::new(),
+            inline_parameters: false,
+            normalize: false,
+            pretty: false,
+            indentation: 0,
+            in_lists_as_arrays: false,
+            deduplicate_parameters: false,
+            strict: false,
+        }
+    }
 
-        (postgres.query, postgres.parameters)
+    /// Renders `IN` lists of parameters as a single array parameter, so the
+    /// query text stays the same no matter how long the list is.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("id".in_selection(Row::from(vec![1, 2, 3])));
+    ///
+    /// let (sql, params) = Postgres::new().in_lists_as_arrays().render(query);
+    ///
+    /// assert_eq!(r#"SELECT "users".* FROM "users" WHERE "id" = ANY($1)"#, sql);
+    /// assert_eq!(vec![Value::from(vec![1, 2, 3])], params);
+    /// # }
+    /// ```
+    pub fn in_lists_as_arrays(mut self) -> Self {
+        self.in_lists_as_arrays = true;
+        self
     }
 
-    fn write<D: fmt::Display>(&mut self, s: D) {
-        write!(&mut self.query, "{s}")
-            .expect("we ran out of memory or something else why write failed");
+    /// Reuses the placeholder of an earlier parameter when the same value of
+    /// the same type is bound again, shrinking the parameter list.
+    /// Only numbered placeholders can be referenced more than once: renderers
+    /// with positional `?` placeholders always bind one parameter per use.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("tenant_id".equals(1).or("owner_tenant_id".equals(1)));
+    ///
+    /// let (sql, params) = Postgres::new().deduplicate_parameters().render(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" WHERE ("tenant_id" = $1 OR "owner_tenant_id" = $1)"#,
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # }
+    /// ```
+    pub fn deduplicate_parameters(mut self) -> Self {
+        self.deduplicate_parameters = true;
+        self
     }
 
-    fn add_parameter(&mut self, value: Value) {
-        self.parameters.push(value);
+    /// Breaks clauses onto their own lines and indents nested queries and join
+    /// conditions, e.g. for logs and snapshot tests. The query means the same
+    /// as in the default, compact form.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut posts = Select::from_table("posts");
+    /// posts.column("title");
+    /// posts.so_that(Column::from(("posts", "user_id")).equals(Column::from(("users", "id"))));
+    ///
+    /// let mut query = Select::from_table("users");
+    /// query.column("name");
+    /// query.value(Expression::from(posts).alias("title"));
+    /// query.so_that("id".equals(1));
+    /// query.limit(1);
+    ///
+    /// let (sql, _) = Postgres::new().pretty().render(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "name", (
+    ///   SELECT "title"
+    ///   FROM "posts"
+    ///   WHERE "posts"."user_id" = "users"."id"
+    /// ) AS "title"
+    /// FROM "users"
+    /// WHERE "id" = $1
+    /// LIMIT $2"#,
+    ///     sql
+    /// );
+    /// # }
+    /// ```
+    pub fn pretty(mut self) -> Self {
+        self.pretty = true;
+        self
     }
 
-    fn parameter_substitution(&mut self) {
-        self.write("$");
-        self.write(self.parameters.len())
+    /// Rejects queries with raw SQL: `raw` and `raw_with_params` expressions
+    /// and raw comparison operators. The returned renderer only renders
+    /// through fallible methods, which return an error for such queries.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("id".compare_raw("=", raw("ANY('{1,2}')")));
+    ///
+    /// let error = Postgres::new().strict().try_render(query).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     "Raw SQL is not allowed in strict mode, found: `=`, `ANY('{1,2}')`.",
+    ///     error.to_string()
+    /// );
+    /// # }
+    /// ```
+    pub fn strict(mut self) -> StrictPostgres {
+        self.strict = true;
+        StrictPostgres(self)
     }
 
-    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
-        match (limit, offset) {
-            (Some(limit), Some(offset)) => {
-                self.write(" LIMIT ");
-                self.visit_parameterized(Value::from(limit));
+    /// Like [render](#method.render), but returns an error if the query needs
+    /// more parameters than the database accepts.
+    pub fn try_render<'a, Q>(mut self, query: Q) -> anyhow::Result<(String, Vec<SqlValue>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.try_visit_query(query.into())?;
 
-                self.write(" OFFSET ");
-                self.visit_parameterized(Value::from(offset))
-            }
-            (None, Some(offset)) => {
-                self.write(" OFFSET ");
-                self.visit_parameterized(Value::from(offset))
-            }
-            (Some(limit), None) => {
-                self.write(" LIMIT ");
-                self.visit_parameterized(Value::from(limit))
-            }
-            (None, None) => (),
-        }
+        Ok((self.query, self.parameters))
     }
 
-    fn visit_insert(&mut self, insert: Insert<'a>) {
-        self.write("INSERT ");
-
-        if let Some(table) = insert.table.clone() {
-            self.write("INTO ");
-            self.visit_table(table, true);
-        }
+    /// Like [render_into](#method.render_into), but returns an error if the
+    /// query needs more parameters than the database accepts. The buffers
+    /// are returned to the caller in both cases.
+    pub fn try_render_into<'a, Q>(
+        mut self,
+        query: Q,
+        sql: &mut String,
+        parameters: &mut Vec<SqlValue>,
+    ) -> anyhow::Result<()>
+    where
+        Q: Into<Query<'a>>,
+    {
+        sql.clear();
+        parameters.clear();
 
-        match insert.values {
-            Expression {
-                kind: ExpressionKind::Row(row),
-                ..
-            } => {
-                if row.values.is_empty() {
-                    self.write(" DEFAULT VALUES");
-                } else {
-                    let columns = insert.columns.len();
+        self.query = mem::take(sql);
+        self.parameters = mem::take(parameters);
 
-                    self.write(" (");
-                    for (i, c) in insert.columns.into_iter().enumerate() {
-                        self.visit_column(c.name.into_owned().into());
+        let result = self.try_visit_query(query.into());
 
-                        if i < (columns - 1) {
-                            self.write(",");
-                        }
-                    }
+        *sql = self.query;
+        *parameters = self.parameters;
 
-                    self.write(")");
-                    self.write(" VALUES ");
-                    self.visit_row(row);
-                }
-            }
-            Expression {
-                kind: ExpressionKind::Values(values),
-                ..
-            } => {
-                let columns = insert.columns.len();
+        result
+    }
 
-                self.write(" (");
-                for (i, c) in insert.columns.into_iter().enumerate() {
-                    self.visit_column(c.name.into_owned().into());
+    /// Like [render_with_types](#method.render_with_types), but returns an
+    /// error if the query needs more parameters than the database accepts.
+    pub fn try_render_with_types<'a, Q>(
+        mut self,
+        query: Q,
+    ) -> anyhow::Result<(String, Vec<SqlValue>, Vec<Option<SqlType>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.try_visit_query(query.into())?;
 
-                    if i < (columns - 1) {
-                        self.write(",");
-                    }
-                }
+        Ok((self.query, self.parameters, self.parameter_types))
+    }
 
-                self.write(")");
-                self.write(" VALUES ");
-                let values_len = values.len();
+    /// Like [render_template](#method.render_template), but returns an error
+    /// if the query needs more parameters than the database accepts.
+    pub fn try_render_template<'a, Q>(mut self, query: Q) -> anyhow::Result<Template>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.try_visit_query(query.into())?;
+
+        Ok(Template {
+            sql: self.query,
+            parameters: self.parameters,
+            parameter_types: self.parameter_types,
+            placeholders: self.placeholders,
+        })
+    }
 
-                for (i, row) in values.into_iter().enumerate() {
-                    self.visit_row(row);
+    /// Lists the raw SQL fragments of the query in the order they are
+    /// rendered, e.g. to audit that a query has none. Other renders only
+    /// record the fragments in strict mode.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("posts");
+    /// query.value(raw_with_params("ts_rank(?, ?)", [Column::from("a"), Column::from("b")]));
+    /// query.so_that("id".equals(1));
+    ///
+    /// assert_eq!(vec!["ts_rank(?, ?)"], Postgres::raw_fragments(query));
+    /// # }
+    /// ```
+    pub fn raw_fragments<'a, Q>(query: Q) -> Vec<String>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mut postgres = Postgres {
+            strict: true,
+            ..Postgres::new()
+        };
 
-                    if i < (values_len - 1) {
-                        self.write(", ");
-                    }
-                }
-            }
-            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr)),
-        }
+        Postgres::visit_query(&mut postgres, query.into());
 
-        match insert.on_conflict {
-            Some(OnConflict::DoNothing) => self.write(" ON CONFLICT DO NOTHING"),
-            Some(OnConflict::Update(update, constraints)) => {
-                self.write(" ON CONFLICT");
-                self.columns_to_bracket_list(constraints);
-                self.write(" DO ");
+        postgres.raw_fragments
+    }
 
-                self.visit_upsert(update);
-            }
-            None => (),
-        }
+    /// Convert the given `Query` to an SQL string and a vector of parameters,
+    /// using the settings of this renderer.
+    pub fn render<'a, Q>(mut self, query: Q) -> (String, Vec<SqlValue>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.visit_buffered_query(query.into());
 
-        if let Some(returning) = insert.returning {
-            if !returning.is_empty() {
-                let values = returning.into_iter().map(|r| r.into()).collect();
-                self.write(" RETURNING ");
-                self.visit_columns(values);
-            }
-        };
+        (self.query, self.parameters)
     }
 
-    fn visit_delete(&mut self, delete: Delete<'a>) {
-        self.write("DELETE FROM ");
-        self.visit_table(delete.table, true);
+    /// Like [render](#method.render), but renders into the given buffers,
+    /// which can be reused between queries to save allocations. Both buffers
+    /// are cleared first.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut sql = String::with_capacity(4096);
+    /// let mut params = Vec::with_capacity(128);
+    ///
+    /// for id in 1..=2 {
+    ///     let mut query = Select::from_table("users");
+    ///     query.so_that("id".equals(id));
+    ///
+    ///     Postgres::new().render_into(query, &mut sql, &mut params);
+    ///
+    ///     assert_eq!(r#"SELECT "users".* FROM "users" WHERE "id" = $1"#, sql);
+    ///     assert_eq!(vec![Value::from(id)], params);
+    /// }
+    /// # }
+    /// ```
+    pub fn render_into<'a, Q>(mut self, query: Q, sql: &mut String, parameters: &mut Vec<SqlValue>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        sql.clear();
+        parameters.clear();
 
-        if let Some(conditions) = delete.conditions {
-            self.write(" WHERE ");
-            self.visit_conditions(conditions);
-        }
+        self.query = mem::take(sql);
+        self.parameters = mem::take(parameters);
 
-        if let Some(returning) = delete.returning {
-            self.write(" RETURNING ");
+        self.visit_buffered_query(query.into());
 
-            let length = returning.len();
+        *sql = self.query;
+        *parameters = self.parameters;
+    }
 
-            for (i, expression) in returning.into_iter().enumerate() {
-                self.visit_expression(expression);
+    /// Like [render](#method.render), but writes the SQL string to the given
+    /// writer while rendering, e.g. into the buffer of a wire protocol encoder,
+    /// and returns the parameters. The query is written in many small pieces,
+    /// so wrap unbuffered writers such as sockets in a `BufWriter`. Writing
+    /// stops at the first error, which is returned after rendering.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("id".equals(1));
+    ///
+    /// let mut buffer = Vec::new();
+    /// let params = Postgres::new().render_to_writer(query, &mut buffer)?;
+    ///
+    /// assert_eq!(br#"SELECT "users".* FROM "users" WHERE "id" = $1"#, buffer.as_slice());
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_to_writer<'a, Q, W>(self, query: Q, writer: &mut W) -> io::Result<Vec<SqlValue>>
+    where
+        Q: Into<Query<'a>>,
+        W: io::Write,
+    {
+        let mut renderer = self.with_sink(IoSink {
+            writer,
+            error: None,
+        });
 
-                if i < (length - 1) {
-                    self.write(", ");
-                }
-            }
+        PostgresRenderer::visit_query(&mut renderer, query.into());
+
+        match renderer.query.error {
+            Some(error) => Err(error),
+            None => Ok(renderer.parameters),
         }
     }
 
-    fn visit_aggregate_to_string(&mut self, value: Expression<'a>) {
-        self.write("ARRAY_TO_STRING");
-        self.write("(");
-        self.write("ARRAY_AGG");
-        self.write("(");
-        self.visit_expression(value);
-        self.write(")");
-        self.write("','");
-        self.write(")")
-    }
+    /// Like [render_to_writer](#method.render_to_writer), but writes the SQL
+    /// string to a `fmt::Write` sink, such as a `fmt::Formatter`.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() -> std::fmt::Result {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("id".equals(1));
+    ///
+    /// let mut sql = String::from("-- users\n");
+    /// let params = Postgres::new().render_to_fmt(query, &mut sql)?;
+    ///
+    /// assert_eq!("-- users\nSELECT \"users\".* FROM \"users\" WHERE \"id\" = $1", sql);
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_to_fmt<'a, Q, W>(
+        self,
+        query: Q,
+        writer: &mut W,
+    ) -> Result<Vec<SqlValue>, fmt::Error>
+    where
+        Q: Into<Query<'a>>,
+        W: fmt::Write,
+    {
+        let mut renderer = self.with_sink(FmtSink {
+            writer,
+            failed: false,
+        });
 
-    fn visit_equals(&mut self, left: Expression<'a>, right: Expression<'a>) {
-        self.visit_expression(left);
-        self.write(" = ");
-        self.visit_expression(right);
-    }
+        PostgresRenderer::visit_query(&mut renderer, query.into());
 
-    fn visit_not_equals(&mut self, left: Expression<'a>, right: Expression<'a>) {
-        self.visit_expression(left);
-        self.write(" <> ");
-        self.visit_expression(right);
+        match renderer.query.failed {
+            true => Err(fmt::Error),
+            false => Ok(renderer.parameters),
+        }
     }
 
-    fn visit_json_extract(&mut self, json_extract: JsonExtract<'a>) {
-        match json_extract.path {
-            #[cfg(feature = "mysql")]
-            JsonPath::String(_) => {
-                panic!("JSON path string notation is not supported for Postgres")
-            }
-            JsonPath::Array(json_path) => {
-                self.write("(");
-                self.visit_expression(*json_extract.column);
-
-                if json_extract.extract_as_string {
-                    self.write("#>>");
-                } else {
-                    self.write("#>");
-                }
+    /// Like [render](#method.render), but also returns the SQL type of every
+    /// parameter, in the same order as the parameters. The type is the declared
+    /// type of the value, the type the query requires in its position, or the
+    /// type inferred from the value. `None` if the type is unknown, e.g. for
+    /// an untyped `NULL`, leaving the database to infer it.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("name".equals("Musti").and("parent".equals(Value::Null)));
+    /// query.limit(10);
+    ///
+    /// let (sql, _, types) = Postgres::new().render_with_types(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" WHERE ("name" = $1 AND "parent" = $2) LIMIT $3"#,
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Some(SqlType::Text), None, Some(SqlType::BigInt)], types);
+    /// # }
+    /// ```
+    pub fn render_with_types<'a, Q>(
+        mut self,
+        query: Q,
+    ) -> (String, Vec<SqlValue>, Vec<Option<SqlType>>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.visit_buffered_query(query.into());
 
-                // We use the `ARRAY[]::text[]` notation to better handle escaped character
-                // The text protocol used when sending prepared statement doesn't seem to work well with escaped characters
-                // when using the '{a, b, c}' string array notation.
-                self.surround_with("ARRAY[", "]::text[]", |s| {
-                    let len = json_path.len();
-                    for (index, path) in json_path.into_iter().enumerate() {
-                        s.visit_parameterized(Value::String(path.to_string()));
-                        if index < len - 1 {
-                            s.write(", ");
-                        }
-                    }
-                });
+        (self.query, self.parameters, self.parameter_types)
+    }
 
-                self.write(")");
+    /// Like [render](#method.render), but returns a template with the
+    /// parameter index of every [placeholder](../ast/fn.placeholder.html), to
+    /// bind their values before executing the query.
+    pub fn render_template<'a, Q>(mut self, query: Q) -> Template
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.visit_buffered_query(query.into());
 
-                if !json_extract.extract_as_string {
-                    self.write("::jsonb");
-                }
-            }
+        Template {
+            sql: self.query,
+            parameters: self.parameters,
+            parameter_types: self.parameter_types,
+            placeholders: self.placeholders,
         }
     }
 
-    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) {
-        self.write("(");
-        self.visit_expression(*json_unquote.expr);
-        self.write("#>>ARRAY[]::text[]");
-        self.write(")");
+    /// Convert the given `Query` to an SQL string, a vector of parameters and
+    /// the SQL types of the parameters, using the default settings.
+    pub fn build_with_types<'a, Q>(query: Q) -> (String, Vec<SqlValue>, Vec<Option<SqlType>>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        Postgres::new().render_with_types(query)
     }
 
-    fn visit_array_contains(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
-        if not {
-            self.write("( NOT ");
+    /// Moves the settings and the parameters into a renderer writing into
+    /// the given sink.
+    fn with_sink<S>(self, sink: S) -> PostgresRenderer<S> {
+        PostgresRenderer {
+            query: sink,
+            parameters: self.parameters,
+            parameter_types: self.parameter_types,
+            parameter_positions: self.parameter_positions,
+            placeholders: self.placeholders,
+            raw_fragments: self.raw_fragments,
+            inline_parameters: self.inline_parameters,
+            normalize: self.normalize,
+            pretty: self.pretty,
+            indentation: self.indentation,
+            in_lists_as_arrays: self.in_lists_as_arrays,
+            deduplicate_parameters: self.deduplicate_parameters,
+            strict: self.strict,
         }
+    }
 
-        self.visit_expression(left);
-        self.write(" @> ");
-        self.visit_expression(right);
+    /// Visits the query into buffers with room for a typical query.
+    fn visit_buffered_query(&mut self, query: Query<'_>) {
+        // A no-op for reused buffers with enough capacity.
+        self.query.reserve(4096);
+        self.parameters.reserve(128);
 
-        if not {
-            self.write(" )");
-        }
+        Postgres::visit_query(self, query);
     }
 
-    fn visit_array_contained(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
-        if not {
-            self.write("( NOT ");
+    /// Visits the query, failing if it has raw SQL in strict mode or needs
+    /// more parameters than the database accepts.
+    fn try_visit_query(&mut self, query: Query<'_>) -> anyhow::Result<()> {
+        self.visit_buffered_query(query);
+        self.check_raw_fragments()?;
+
+        if self.parameters.len() > Self::MAX_PARAMETERS {
+            return Err(anyhow!(
+                "The query has {} parameters, more than the maximum of {}.",
+                self.parameters.len(),
+                Self::MAX_PARAMETERS
+            ));
         }
 
-        self.visit_expression(left);
-        self.write(" <@ ");
-        self.visit_expression(right);
-
-        if not {
-            self.write(" )");
-        }
+        Ok(())
     }
 
-    fn visit_array_overlaps(&mut self, left: Expression<'a>, right: Expression<'a>) {
-        self.visit_expression(left);
-        self.write(" && ");
-        self.visit_expression(right);
+    /// Renders the query with every parameter written inline as an escaped SQL
+    /// literal, for logging and `EXPLAIN`. The result can be pasted into `psql`,
+    /// but should never be sent to the database instead of the parameterized
+    /// query.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("name".equals("O'Brien"));
+    /// query.limit(10);
+    ///
+    /// let sql = Postgres::debug_sql(query);
+    ///
+    /// assert_eq!(r#"SELECT "users".* FROM "users" WHERE "name" = 'O''Brien' LIMIT 10"#, sql);
+    /// # }
+    /// ```
+    pub fn debug_sql<'a, Q>(query: Q) -> String
+    where
+        Q: Into<Query<'a>>,
+    {
+        let postgres = Postgres {
+            inline_parameters: true,
+            ..Postgres::new()
+        };
+
+        postgres.render(query).0
     }
 
-    fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) {
-        self.write("(");
-        self.visit_expression(*extract.expr);
-        self.write("->-1");
-        self.write(")");
+    /// Renders the normalized form of the query: every parameter and
+    /// placeholder is written as `?`, lists of parameters or of rows of
+    /// parameters in `IN` as `(...)`, and comments are left out. Queries of the same shape render the same,
+    /// whatever their parameter values, e.g. for metrics labels.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("id".in_selection(Row::from(vec![1, 2, 3])).and("name".equals("Musti")));
+    /// query.tag("request_id", "42");
+    ///
+    /// let sql = Postgres::normalized_sql(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" WHERE ("id" IN (...) AND "name" = ?)"#,
+    ///     sql
+    /// );
+    /// # }
+    /// ```
+    pub fn normalized_sql<'a, Q>(query: Q) -> String
+    where
+        Q: Into<Query<'a>>,
+    {
+        let postgres = Postgres {
+            normalize: true,
+            ..Postgres::new()
+        };
+
+        postgres.render(query).0
     }
+}
+
+impl<W: fmt::Write> PostgresRenderer<W> {
+    /// Visits the left side of a pattern match.
+    fn visit_text_operand(&mut self, left: Expression<'_>) {
+        let need_cast = matches!(&left.kind, ExpressionKind::Column(_));
+        self.visit_expression(left);
+
+        // NOTE: Pg is strongly typed, LIKE comparisons are only between strings.
+        // to avoid problems with types without implicit casting we explicitly cast to text
+        if need_cast {
+            self.write("::text");
+        }
+    }
+
+    /// Starts a new line at the current indentation.
+    fn write_line_break(&mut self) {
+        self.write("\n");
+        self.write("  ".repeat(self.indentation));
+    }
+
+    fn check_raw_fragments(&self) -> anyhow::Result<()> {
+        if !self.strict || self.raw_fragments.is_empty() {
+            return Ok(());
+        }
+
+        let fragments: Vec<_> = self
+            .raw_fragments
+            .iter()
+            .map(|fragment| format!("`{fragment}`"))
+            .collect();
+
+        Err(anyhow!(
+            "Raw SQL is not allowed in strict mode, found: {}.",
+            fragments.join(", ")
+        ))
+    }
+
+    /// Visits the expression, converting it to `sql_type` if it is a parameter
+    /// without a declared type. Values that do not convert are left untyped.
+    fn visit_expression_as(&mut self, expression: Expression<'_>, sql_type: SqlType) {
+        match expression {
+            Expression {
+                kind: ExpressionKind::Parameterized(value),
+                alias: None,
+            } if !matches!(value, SqlValue::Typed(..)) => {
+                let (value, sql_type) = value.coerce(sql_type);
+                self.visit_typed_parameter(value, sql_type)
+            }
+            Expression {
+                kind: ExpressionKind::Placeholder(name),
+                alias: None,
+            } => self.visit_typed_placeholder(name, Some(sql_type)),
+            expression => self.visit_expression(expression),
+        }
+    }
+
+    /// Writes the parameter slot of a placeholder, adding the slot with the
+    /// given type on its first use.
+    fn visit_typed_placeholder(&mut self, name: Cow<'_, str>, sql_type: Option<SqlType>) {
+        if self.normalize {
+            self.write("?");
+            return;
+        }
+
+        // Written as a psql variable interpolated as a literal, so the debug
+        // output still runs in psql.
+        if self.inline_parameters {
+            self.write(format!(":'{}'", name.replace('\'', "''")));
+            return;
+        }
+
+        match self.placeholders.get(name.as_ref()) {
+            Some(&index) => {
+                if self.parameter_types[index].is_none() {
+                    self.parameter_types[index] = sql_type;
+                }
+
+                self.write("$");
+                self.write(index + 1);
+            }
+            None => {
+                self.placeholders
+                    .insert(name.into_owned(), self.parameters.len());
+
+                self.parameters.push(SqlValue::Null);
+                self.parameter_types.push(sql_type);
+                self.parameter_substitution()
+            }
+        }
+    }
+
+    /// Adds the parameter with the given type and writes its placeholder. With
+    /// deduplication, an equal earlier parameter of the same type is reused.
+    fn visit_typed_parameter(&mut self, value: SqlValue, sql_type: Option<SqlType>) {
+        if self.normalize {
+            self.write("?");
+            return;
+        }
+
+        if self.inline_parameters {
+            self.write_literal(value);
+            return;
+        }
+
+        if self.deduplicate_parameters {
+            let mut hasher = DefaultHasher::new();
+            value.hash_into(&mut hasher);
+            sql_type.hash(&mut hasher);
+
+            let positions = self.parameter_positions.entry(hasher.finish()).or_default();
+
+            let existing = positions
+                .iter()
+                .copied()
+                .find(|&i| self.parameters[i] == value && self.parameter_types[i] == sql_type);
+
+            if let Some(i) = existing {
+                self.write("$");
+                self.write(i + 1);
+
+                return;
+            }
+
+            positions.push(self.parameters.len());
+        }
+
+        self.parameters.push(value);
+        self.parameter_types.push(sql_type);
+        self.parameter_substitution()
+    }
+
+    /// Writes the value as a literal. Strings are quoted, arrays use the
+    /// `ARRAY[..]` constructor and values without a literal syntax of their
+    /// own are cast from a quoted string.
+    fn write_literal(&mut self, value: SqlValue) {
+        match value {
+            SqlValue::Null => self.write("NULL"),
+            SqlValue::Boolean(b) => self.write(if b { "true" } else { "false" }),
+            SqlValue::Int16(i) => self.write(i),
+            SqlValue::Int32(i) => self.write(i),
+            SqlValue::Int64(i) => self.write(i),
+            SqlValue::Float(f) if f.is_finite() => self.write(f),
+            SqlValue::Float(f) => self.write_cast(&f64::from(f).to_string(), "float4"),
+            SqlValue::Double(f) if f.is_finite() => self.write(f),
+            SqlValue::Double(f) => self.write_cast(&f.to_string(), "float8"),
+            SqlValue::Numeric(n) => self.write_cast(&n, "numeric"),
+            SqlValue::Text(s) => self.write(quote_string(&s)),
+            SqlValue::Bytes(bytes) => {
+                let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                self.write_cast(&format!("\\x{hex}"), "bytea")
+            }
+            SqlValue::Uuid(uuid) => self.write_cast(&format_uuid(uuid), "uuid"),
+            SqlValue::Date(date) => self.write_cast(&date, "date"),
+            SqlValue::Timestamp(timestamp) => self.write_cast(&timestamp, "timestamp"),
+            SqlValue::TimestampTz(timestamp) => self.write_cast(&timestamp, "timestamptz"),
+            SqlValue::Json(json) => self.write_cast(&json.to_string(), "jsonb"),
+            SqlValue::Array(values) if values.is_empty() => self.write("'{}'"),
+            SqlValue::Array(values) => {
+                let len = values.len();
+
+                self.surround_with("ARRAY[", "]", |s| {
+                    for (i, value) in values.into_iter().enumerate() {
+                        s.write_literal(value);
+
+                        if i < (len - 1) {
+                            s.write(", ");
+                        }
+                    }
+                });
+            }
+            SqlValue::Typed(value, sql_type) => {
+                self.surround_with("(", ")", |s| s.write_literal(*value));
+                self.write("::");
+                self.write(type_name(&sql_type));
+            }
+        }
+    }
+
+    /// Writes a quoted string cast to the given type.
+    fn write_cast(&mut self, value: &str, type_name: &str) {
+        self.write(quote_string(value));
+        self.write("::");
+        self.write(type_name);
+    }
+
+    /// Writes the key-value pairs of a `json_build_object` call. Keys are
+    /// written as escaped string literals.
+    fn write_json_build_object_arguments<'a>(
+        &mut self,
+        values: Vec<(Cow<'a, str>, Expression<'a>)>,
+    ) {
+        let values_length = values.len();
+
+        self.surround_with("(", ")", |s| {
+            for (i, (name, expression)) in values.into_iter().enumerate() {
+                s.write(quote_string(&name));
+                s.write(", ");
+                s.visit_expression(expression);
+
+                if i < (values_length - 1) {
+                    s.write(",");
+                }
+            }
+        });
+    }
+}
+
+/// A [Postgres](type.Postgres.html) renderer that rejects raw SQL, created
+/// with [strict](type.Postgres.html#method.strict). It only renders through
+/// fallible methods, which return an error if the query has raw SQL or needs
+/// more parameters than the database accepts.
+#[cfg_attr(feature = "docs", doc(cfg(feature = "postgresql")))]
+pub struct StrictPostgres(Postgres);
+
+impl StrictPostgres {
+    /// See [Postgres::try_render](type.Postgres.html#method.try_render).
+    pub fn try_render<'a, Q>(self, query: Q) -> anyhow::Result<(String, Vec<SqlValue>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.0.try_render(query)
+    }
+
+    /// See [Postgres::try_render_into](type.Postgres.html#method.try_render_into).
+    pub fn try_render_into<'a, Q>(
+        self,
+        query: Q,
+        sql: &mut String,
+        parameters: &mut Vec<SqlValue>,
+    ) -> anyhow::Result<()>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.0.try_render_into(query, sql, parameters)
+    }
+
+    /// See [Postgres::try_render_with_types](type.Postgres.html#method.try_render_with_types).
+    pub fn try_render_with_types<'a, Q>(
+        self,
+        query: Q,
+    ) -> anyhow::Result<(String, Vec<SqlValue>, Vec<Option<SqlType>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.0.try_render_with_types(query)
+    }
+
+    /// See [Postgres::try_render_template](type.Postgres.html#method.try_render_template).
+    pub fn try_render_template<'a, Q>(self, query: Q) -> anyhow::Result<Template>
+    where
+        Q: Into<Query<'a>>,
+    {
+        self.0.try_render_template(query)
+    }
+}
+
+/// An `io::Write` sink for the renderer. Keeps the first error and ignores
+/// the rest of the query after it, so rendering itself never fails.
+struct IoSink<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoSink<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_none() {
+            if let Err(error) = self.writer.write_all(s.as_bytes()) {
+                self.error = Some(error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A `fmt::Write` sink for the renderer, ignoring the rest of the query after
+/// the first error.
+struct FmtSink<W> {
+    writer: W,
+    failed: bool,
+}
+
+impl<W: fmt::Write> fmt::Write for FmtSink<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.failed {
+            self.failed = self.writer.write_str(s).is_err();
+        }
+
+        Ok(())
+    }
+}
+
+/// True if the expression is known to return `jsonb`.
+fn returns_json(expression: &Expression<'_>) -> bool {
+    match &expression.kind {
+        ExpressionKind::Function(function) => function.returns_json(),
+        _ => false,
+    }
+}
+
+/// The name of the type in PostgreSQL.
+fn type_name(sql_type: &SqlType) -> String {
+    match sql_type {
+        SqlType::Boolean => "bool".to_string(),
+        SqlType::SmallInt => "int2".to_string(),
+        SqlType::Integer => "int4".to_string(),
+        SqlType::BigInt => "int8".to_string(),
+        SqlType::Real => "float4".to_string(),
+        SqlType::DoublePrecision => "float8".to_string(),
+        SqlType::Numeric => "numeric".to_string(),
+        SqlType::Text => "text".to_string(),
+        SqlType::Bytes => "bytea".to_string(),
+        SqlType::Uuid => "uuid".to_string(),
+        SqlType::Date => "date".to_string(),
+        SqlType::Timestamp => "timestamp".to_string(),
+        SqlType::TimestampTz => "timestamptz".to_string(),
+        SqlType::Json => "json".to_string(),
+        SqlType::Jsonb => "jsonb".to_string(),
+        SqlType::Array(element) => format!("{}[]", type_name(element)),
+    }
+}
+
+/// True if the expression is a parameter or a row of parameters, which
+/// normalized `IN` lists leave out.
+fn is_parameter_or_row(expression: &Expression<'_>) -> bool {
+    match &expression.kind {
+        ExpressionKind::Parameterized(_) => true,
+        ExpressionKind::Row(row) => row.values.iter().all(is_parameter_or_row),
+        _ => false,
+    }
+}
+
+/// Quotes a string as a PostgreSQL literal. Single quotes are doubled, and if
+/// the string contains backslashes, the escape string syntax is used so the
+/// result does not depend on `standard_conforming_strings`.
+fn quote_string(s: &str) -> String {
+    let escaped = s.replace('\'', "''");
+
+    if escaped.contains('\\') {
+        format!("E'{}'", escaped.replace('\\', "\\\\"))
+    } else {
+        format!("'{escaped}'")
+    }
+}
+
+impl<'a, W: fmt::Write> Renderer<'a> for PostgresRenderer<W> {
+    const C_BACKTICK_OPEN: &'static str = "\"";
+    const C_BACKTICK_CLOSE: &'static str = "\"";
+    const C_WILDCARD: &'static str = "%";
+    const C_LIKE_ESCAPE: char = '\\';
+    const MAX_PARAMETERS: usize = 65535;
+
+    fn build<Q>(query: Q) -> (String, Vec<SqlValue>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        Postgres::new().render(query)
+    }
+
+    fn write<D: fmt::Display>(&mut self, s: D) {
+        write!(&mut self.query, "{s}")
+            .expect("we ran out of memory or something else why write failed");
+    }
+
+    fn add_parameter(&mut self, value: SqlValue) {
+        self.parameter_types.push(value.sql_type());
+        self.parameters.push(value);
+    }
+
+    fn parameter_substitution(&mut self) {
+        self.write("$");
+        self.write(self.parameters.len())
+    }
+
+    fn visit_placeholder(&mut self, name: Cow<'a, str>) {
+        self.visit_typed_placeholder(name, None)
+    }
+
+    fn is_normalizing(&self) -> bool {
+        self.normalize
+    }
+
+    fn write_clause(&mut self, clause: &str) {
+        if self.pretty {
+            self.write_line_break();
+        } else {
+            self.write(" ");
+        }
+
+        self.write(clause);
+    }
+
+    fn indented<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.indentation += 1;
+        f(self);
+        self.indentation -= 1;
+    }
+
+    fn visit_nested<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        if !self.pretty {
+            self.surround_with("(", ")", f);
+            return;
+        }
 
-    fn visit_json_extract_first_array_item(&mut self, extract: JsonExtractFirstArrayElem<'a>) {
         self.write("(");
-        self.visit_expression(*extract.expr);
-        self.write("->0");
+
+        self.indented(|s| {
+            s.write_line_break();
+            f(s)
+        });
+
+        self.write_line_break();
         self.write(")");
     }
 
-    fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) {
-        self.write("JSONB_TYPEOF");
-        self.write("(");
+    fn register_raw_fragment(&mut self, fragment: &str) {
+        if self.strict {
+            self.raw_fragments.push(fragment.to_string());
+        }
+    }
+
+    fn visit_parameterized(&mut self, value: SqlValue) {
+        let sql_type = value.sql_type();
+        self.visit_typed_parameter(value, sql_type);
+    }
+
+    fn visit_in_list(&mut self, left: Expression<'a>, list: Row<'a>, negate: bool) {
+        let all_parameters = list
+            .values
+            .iter()
+            .all(|value| matches!(value.kind, ExpressionKind::Parameterized(_)));
+
+        // Lists of any length have the same shape.
+        if self.normalize && list.values.iter().all(is_parameter_or_row) {
+            self.visit_expression(left);
+            self.write(if negate { " NOT IN (...)" } else { " IN (...)" });
+
+            return;
+        }
+
+        if !self.in_lists_as_arrays || !all_parameters {
+            self.visit_expression(left);
+            self.write(if negate { " NOT IN " } else { " IN " });
+            self.visit_row(list);
+
+            return;
+        }
+
+        let values = list
+            .values
+            .into_iter()
+            .filter_map(|value| match value.kind {
+                ExpressionKind::Parameterized(value) => Some(value),
+                _ => None,
+            })
+            .collect();
+
+        if negate {
+            self.write("NOT (");
+        }
+
         self.visit_expression(left);
+        self.write(" = ANY(");
+        self.visit_parameterized(SqlValue::Array(values));
         self.write(")");
 
-        if not {
-            self.write(" != ");
+        if negate {
+            self.write(")");
+        }
+    }
+
+    fn visit_multiple_tuple_comparison(&mut self, left: Row<'a>, right: Values<'a>, negate: bool) {
+        let all_parameters = right
+            .rows
+            .iter()
+            .all(|row| row.values.iter().all(is_parameter_or_row));
+
+        self.visit_row(left);
+        self.write(if negate { " NOT IN " } else { " IN " });
+
+        // Lists of any length have the same shape.
+        if self.normalize && all_parameters {
+            self.write("(...)");
         } else {
-            self.write(" = ");
+            self.visit_values(right)
         }
+    }
 
-        match json_type {
-            JsonType::Array => self.visit_expression(Value::String("array".to_string()).into()),
-            JsonType::Boolean => self.visit_expression(Value::String("boolean".to_string()).into()),
-            JsonType::Number => self.visit_expression(Value::String("number".to_string()).into()),
-            JsonType::Object => self.visit_expression(Value::String("object".to_string()).into()),
-            JsonType::String => self.visit_expression(Value::String("string".to_string()).into()),
-            JsonType::Null => self.visit_expression(Value::String("null".to_string()).into()),
-            JsonType::ColumnRef(column) => {
-                self.write("JSONB_TYPEOF");
-                self.write("(");
-                self.visit_column(*column);
-                self.write("::jsonb)")
+    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => {
+                self.write_clause("LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)));
+
+                self.write_clause("OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (None, Some(offset)) => {
+                self.write_clause("OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (Some(limit), None) => {
+                self.write_clause("LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)))
             }
+            (None, None) => (),
         }
     }
 
-    fn visit_like(&mut self, left: Expression<'a>, right: Expression<'a>) {
-        let need_cast = matches!(&left.kind, ExpressionKind::Column(_));
-        self.visit_expression(left);
+    fn visit_insert(&mut self, insert: Insert<'a>) {
+        self.write("INSERT ");
 
-        // NOTE: Pg is strongly typed, LIKE comparisons are only between strings.
-        // to avoid problems with types without implicit casting we explicitly cast to text
-        if need_cast {
-            self.write("::text");
+        if let Some(table) = insert.table.clone() {
+            self.write("INTO ");
+            self.visit_table(table, true);
         }
 
-        self.write(" LIKE ");
-        self.visit_expression(right);
-    }
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => {
+                if row.values.is_empty() {
+                    self.write_clause("DEFAULT VALUES");
+                } else {
+                    let columns = insert.columns.len();
 
-    fn visit_not_like(&mut self, left: Expression<'a>, right: Expression<'a>) {
-        let need_cast = matches!(&left.kind, ExpressionKind::Column(_));
-        self.visit_expression(left);
+                    self.write(" (");
+                    for (i, c) in insert.columns.into_iter().enumerate() {
+                        self.visit_column(c.name.into_owned().into());
+
+                        if i < (columns - 1) {
+                            self.write(",");
+                        }
+                    }
+
+                    self.write(")");
+                    self.write_clause("VALUES ");
+                    self.visit_row(row);
+                }
+            }
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                let columns = insert.columns.len();
+
+                self.write(" (");
+                for (i, c) in insert.columns.into_iter().enumerate() {
+                    self.visit_column(c.name.into_owned().into());
+
+                    if i < (columns - 1) {
+                        self.write(",");
+                    }
+                }
+
+                self.write(")");
+                self.write_clause("VALUES ");
+                let values_len = values.len();
+
+                for (i, row) in values.into_iter().enumerate() {
+                    self.visit_row(row);
+
+                    if i < (values_len - 1) {
+                        self.write(", ");
+                    }
+                }
+            }
+            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr)),
+        }
+
+        match insert.on_conflict {
+            Some(OnConflict::DoNothing) => self.write_clause("ON CONFLICT DO NOTHING"),
+            Some(OnConflict::Update(update, constraints)) => {
+                self.write_clause("ON CONFLICT");
+                self.columns_to_bracket_list(constraints);
+                self.write(" DO ");
+
+                self.visit_upsert(update);
+            }
+            None => (),
+        }
+
+        if let Some(returning) = insert.returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write_clause("RETURNING ");
+                self.visit_columns(values);
+            }
+        };
+
+        self.visit_comment(insert.comment);
+    }
+
+    fn visit_delete(&mut self, delete: Delete<'a>) {
+        self.write("DELETE FROM ");
+        self.visit_table(delete.table, true);
+
+        if let Some(conditions) = delete.conditions {
+            self.write_clause("WHERE ");
+            self.visit_conditions(conditions);
+        }
+
+        if let Some(returning) = delete.returning {
+            self.write_clause("RETURNING ");
+
+            let length = returning.len();
+
+            for (i, expression) in returning.into_iter().enumerate() {
+                self.visit_expression(expression);
+
+                if i < (length - 1) {
+                    self.write(", ");
+                }
+            }
+        }
+
+        self.visit_comment(delete.comment);
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'a>) {
+        self.write("ARRAY_TO_STRING");
+        self.write("(");
+        self.write("ARRAY_AGG");
+        self.write("(");
+        self.visit_expression(value);
+        self.write(")");
+        self.write("','");
+        self.write(")")
+    }
+
+    fn visit_equals(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        let right_is_jsonb = returns_json(&left);
+        self.visit_expression(left);
+        self.write(" = ");
+
+        if right_is_jsonb {
+            self.visit_expression_as(right, SqlType::Jsonb);
+        } else {
+            self.visit_expression(right);
+        }
+    }
+
+    fn visit_not_equals(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        let right_is_jsonb = returns_json(&left);
+        self.visit_expression(left);
+        self.write(" <> ");
+
+        if right_is_jsonb {
+            self.visit_expression_as(right, SqlType::Jsonb);
+        } else {
+            self.visit_expression(right);
+        }
+    }
+
+    fn visit_json_extract(&mut self, json_extract: JsonExtract<'a>) {
+        match json_extract.path {
+            #[cfg(feature = "mysql")]
+            JsonPath::String(_) => {
+                panic!("JSON path string notation is not supported for Postgres")
+            }
+            JsonPath::Array(json_path) => {
+                self.write("(");
+                self.visit_expression(*json_extract.column);
+
+                if json_extract.extract_as_string {
+                    self.write("#>>");
+                } else {
+                    self.write("#>");
+                }
+
+                // We use the `ARRAY[]::text[]` notation to better handle escaped character
+                // The text protocol used when sending prepared statement doesn't seem to work well with escaped characters
+                // when using the '{a, b, c}' string array notation.
+                self.surround_with("ARRAY[", "]::text[]", |s| {
+                    let len = json_path.len();
+                    for (index, path) in json_path.into_iter().enumerate() {
+                        s.visit_parameterized(SqlValue::Text(path.to_string()));
+                        if index < len - 1 {
+                            s.write(", ");
+                        }
+                    }
+                });
+
+                self.write(")");
+
+                if !json_extract.extract_as_string {
+                    self.write("::jsonb");
+                }
+            }
+        }
+    }
+
+    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) {
+        self.write("(");
+        self.visit_expression(*json_unquote.expr);
+        self.write("#>>ARRAY[]::text[]");
+        self.write(")");
+    }
+
+    fn visit_array_contains(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        if not {
+            self.write("( NOT ");
+        }
+
+        let right_is_jsonb = returns_json(&left);
+        self.visit_expression(left);
+        self.write(" @> ");
+
+        if right_is_jsonb {
+            self.visit_expression_as(right, SqlType::Jsonb);
+        } else {
+            self.visit_expression(right);
+        }
+
+        if not {
+            self.write(" )");
+        }
+    }
+
+    fn visit_array_contained(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        if not {
+            self.write("( NOT ");
+        }
+
+        let right_is_jsonb = returns_json(&left);
+        self.visit_expression(left);
+        self.write(" <@ ");
+
+        if right_is_jsonb {
+            self.visit_expression_as(right, SqlType::Jsonb);
+        } else {
+            self.visit_expression(right);
+        }
+
+        if not {
+            self.write(" )");
+        }
+    }
+
+    fn visit_array_overlaps(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.visit_expression(left);
+        self.write(" && ");
+        self.visit_expression(right);
+    }
+
+    fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) {
+        self.write("(");
+        self.visit_expression(*extract.expr);
+        self.write("->-1");
+        self.write(")");
+    }
+
+    fn visit_json_extract_first_array_item(&mut self, extract: JsonExtractFirstArrayElem<'a>) {
+        self.write("(");
+        self.visit_expression(*extract.expr);
+        self.write("->0");
+        self.write(")");
+    }
+
+    fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) {
+        self.write("JSONB_TYPEOF");
+        self.write("(");
+        self.visit_expression(left);
+        self.write(")");
+
+        if not {
+            self.write(" != ");
+        } else {
+            self.write(" = ");
+        }
+
+        match json_type {
+            JsonType::Array => self.visit_expression(SqlValue::Text("array".to_string()).into()),
+            JsonType::Boolean => {
+                self.visit_expression(SqlValue::Text("boolean".to_string()).into())
+            }
+            JsonType::Number => self.visit_expression(SqlValue::Text("number".to_string()).into()),
+            JsonType::Object => self.visit_expression(SqlValue::Text("object".to_string()).into()),
+            JsonType::String => self.visit_expression(SqlValue::Text("string".to_string()).into()),
+            JsonType::Null => self.visit_expression(SqlValue::Text("null".to_string()).into()),
+            JsonType::ColumnRef(column) => {
+                self.write("JSONB_TYPEOF");
+                self.write("(");
+                self.visit_column(*column);
+                self.write("::jsonb)")
+            }
+        }
+    }
+
+    fn visit_like(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.visit_text_operand(left);
+        self.write(" LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_not_like(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.visit_text_operand(left);
+        self.write(" NOT LIKE ");
+        self.visit_expression(right);
+    }
+
+    fn visit_quantified(
+        &mut self,
+        left: Expression<'a>,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        right: Expression<'a>,
+    ) {
+        // A list of values converts into a JSON array, but `ANY` and `ALL`
+        // need a Postgres array.
+        let right = match right.kind {
+            ExpressionKind::Parameterized(SqlValue::Json(Value::Array(values))) => {
+                let values = values.into_iter().map(SqlValue::from).collect();
+                Expression::from(SqlValue::Array(values))
+            }
+            kind => Expression { kind, ..right },
+        };
+
+        self.visit_native_quantified(left, operator, quantifier, right)
+    }
+
+    // The backslash is the default escape character of `LIKE`.
+    fn write_like_escape(&mut self) {}
+
+    fn visit_bitwise_xor(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(" # ");
+            s.visit_expression(right);
+        });
+    }
+
+    fn visit_ilike(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.visit_text_operand(left);
+        self.write(if not { " NOT ILIKE " } else { " ILIKE " });
+        self.visit_expression(right);
+    }
+
+    fn visit_regex_match(
+        &mut self,
+        left: Expression<'a>,
+        right: Expression<'a>,
+        case_insensitive: bool,
+        not: bool,
+    ) {
+        self.visit_text_operand(left);
+
+        match (case_insensitive, not) {
+            (false, false) => self.write(" ~ "),
+            (false, true) => self.write(" !~ "),
+            (true, false) => self.write(" ~* "),
+            (true, true) => self.write(" !~* "),
+        }
+
+        self.visit_expression(right);
+    }
+
+    fn visit_similar_to(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.visit_text_operand(left);
+        self.write(if not {
+            " NOT SIMILAR TO "
+        } else {
+            " SIMILAR TO "
+        });
+        self.visit_expression(right);
+    }
+
+    fn visit_ordering(&mut self, ordering: Ordering<'a>) {
+        let len = ordering.0.len();
+
+        for (i, (value, ordering)) in ordering.0.into_iter().enumerate() {
+            let direction = ordering.map(|dir| match dir {
+                Order::Asc => " ASC",
+                Order::Desc => " DESC",
+                Order::AscNullsFirst => " ASC NULLS FIRST",
+                Order::AscNullsLast => " ASC NULLS LAST",
+                Order::DescNullsFirst => " DESC NULLS FIRST",
+                Order::DescNullsLast => " DESC NULLS LAST",
+            });
+
+            self.visit_expression(value);
+            self.write(direction.unwrap_or(""));
+
+            if i < (len - 1) {
+                self.write(", ");
+            }
+        }
+    }
+
+    fn visit_integer_argument(&mut self, expression: Expression<'a>) {
+        // Parameters are bound as `int8` by default, which the string
+        // functions do not accept.
+        self.visit_expression_as(expression, SqlType::Integer);
+    }
+
+    fn visit_round(&mut self, round: Round<'a>) {
+        self.write("ROUND");
+        self.surround_with("(", ")", |s| match round.digits {
+            // Rounding to decimal digits is only defined for `numeric`.
+            Some(digits) => {
+                s.write("CAST(");
+                s.visit_expression(*round.expression);
+                s.write(" AS numeric)");
+                s.write(", ");
+                s.visit_integer_argument(*digits);
+            }
+            None => s.visit_expression(*round.expression),
+        });
+    }
+
+    fn visit_concat(&mut self, concat: Concat<'a>) {
+        let len = concat.exprs.len();
+
+        self.surround_with("(", ")", |s| {
+            for (i, expr) in concat.exprs.into_iter().enumerate() {
+                s.visit_expression(expr);
+
+                if i < (len - 1) {
+                    s.write(" || ");
+                }
+            }
+        });
+    }
+
+    fn visit_to_jsonb(&mut self, to_jsonb: ToJsonb<'a>) {
+        self.write("to_jsonb(");
+        self.visit_table(to_jsonb.table, false);
+        self.write(".*)");
+    }
+
+    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'a>) {
+        if json_build_object.values.len() <= JSON_BUILD_OBJECT_MAX_PAIRS {
+            self.write("json_build_object");
+            self.write_json_build_object_arguments(json_build_object.values);
+
+            return;
+        }
+
+        // PostgreSQL functions accept at most 100 arguments. Bigger objects are
+        // built in chunks and merged, which requires the `jsonb` variant.
+        let mut values = json_build_object.values.into_iter().peekable();
+
+        self.surround_with("(", ")", |s| {
+            while values.peek().is_some() {
+                let chunk = values.by_ref().take(JSON_BUILD_OBJECT_MAX_PAIRS).collect();
+
+                s.write("jsonb_build_object");
+                s.write_json_build_object_arguments(chunk);
+
+                if values.peek().is_some() {
+                    s.write(" || ");
+                }
+            }
+        });
+    }
+
+    fn visit_json_agg(&mut self, json_agg: JsonAgg<'a>) {
+        self.write("json_agg(");
+
+        if json_agg.distinct {
+            self.write("DISTINCT ");
+        }
+
+        self.visit_expression(json_agg.expression);
+
+        if let Some(ordering) = json_agg.order_by {
+            self.write(" ORDER BY ");
+            self.visit_ordering(ordering);
+        }
+
+        self.write(")");
+    }
+
+    fn visit_encode(&mut self, encode: Encode<'a>) {
+        self.write("encode(");
+        self.visit_expression(encode.expression);
+        self.write(", ");
+
+        match encode.format {
+            EncodeFormat::Base64 => self.write("'base64'"),
+            EncodeFormat::Escape => self.write("'escape'"),
+            EncodeFormat::Hex => self.write("'hex'"),
+        }
+
+        self.write(")");
+    }
+
+    fn visit_join_data(&mut self, data: JoinData<'a>) {
+        if data.lateral {
+            self.write(" LATERAL ");
+        }
+
+        self.visit_table(data.table, true);
+        self.indented(|s| s.write_clause("ON "));
+        self.visit_conditions(data.conditions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::json_build_object, renderer::*};
+
+    fn expected_values<T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value>)
+    where
+        T: Into<Value>,
+    {
+        (
+            String::from(sql),
+            params.into_iter().map(|p| p.into()).collect(),
+        )
+    }
+
+    fn default_params(mut additional: Vec<Value>) -> Vec<Value> {
+        let mut result = Vec::new();
+
+        for param in additional.drain(0..) {
+            result.push(param)
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_single_row_insert_default_values() {
+        let query = Insert::single_into("users");
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!("INSERT INTO \"users\" DEFAULT VALUES", sql);
+        assert_eq!(default_params(vec![]), params);
+    }
+
+    #[test]
+    fn test_single_row_insert() {
+        let expected = expected_values("INSERT INTO \"users\" (\"foo\") VALUES ($1)", vec![10]);
+
+        let mut insert = Insert::single_into("users");
+        insert.value("foo", 10);
+
+        let (sql, params) = Postgres::build(insert);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn test_returning_insert() {
+        let expected = expected_values(
+            "INSERT INTO \"users\" (\"foo\") VALUES ($1) RETURNING \"foo\"",
+            vec![10],
+        );
+
+        let mut query = Insert::single_into("users");
+        query.value("foo", 10);
+
+        let mut query = query.build();
+        query.returning(vec!["foo"]);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn test_insert_on_conflict_update() {
+        let expected = expected_values(
+            "INSERT INTO \"users\" (\"foo\") VALUES ($1) ON CONFLICT (\"foo\") DO UPDATE SET \"foo\" = $2 WHERE \"users\".\"foo\" = $3 RETURNING \"foo\"",
+            vec![10, 3, 1],
+        );
+
+        let mut update = Update::table("users");
+        update.set("foo", 3);
+        update.so_that(("users", "foo").equals(1));
+
+        let mut insert = Insert::single_into("users");
+        insert.value("foo", 10);
+
+        let mut insert = insert.build();
+        insert.returning(vec!["foo"]);
+        insert.on_conflict(OnConflict::Update(update, Vec::from(["foo".into()])));
+
+        let (sql, params) = Postgres::build(insert);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_multi_row_insert() {
+        let expected = expected_values(
+            "INSERT INTO \"users\" (\"foo\") VALUES ($1), ($2)",
+            vec![10, 11],
+        );
+
+        let mut insert = Insert::multi_into("users", vec!["foo"]);
+        insert.values(vec![10]);
+        insert.values(vec![11]);
+
+        let (sql, params) = Postgres::build(insert);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_limit_and_offset_when_both_are_set() {
+        let expected = expected_values(
+            "SELECT \"users\".* FROM \"users\" LIMIT $1 OFFSET $2",
+            vec![10_i64, 2_i64],
+        );
+
+        let mut query = Select::from_table("users");
+        query.limit(10);
+        query.offset(2);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_limit_and_offset_when_only_offset_is_set() {
+        let expected = expected_values("SELECT \"users\".* FROM \"users\" OFFSET $1", vec![10_i64]);
+
+        let mut query = Select::from_table("users");
+        query.offset(10);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_limit_and_offset_when_only_limit_is_set() {
+        let expected = expected_values("SELECT \"users\".* FROM \"users\" LIMIT $1", vec![10_i64]);
+
+        let mut query = Select::from_table("users");
+        query.limit(10);
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_distinct() {
+        let expected_sql = "SELECT DISTINCT \"bar\" FROM \"test\"";
+
+        let mut query = Select::from_table("test");
+        query.column(Column::new("bar"));
+        query.distinct();
+
+        let (sql, _) = Postgres::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_distinct_with_subquery() {
+        let expected_sql = "SELECT DISTINCT (SELECT $1 FROM \"test2\"), \"bar\" FROM \"test\"";
+
+        let mut query = Select::from_table("test");
+
+        query.value({
+            let mut query = Select::from_table("test2");
+            query.value(1);
+
+            query
+        });
+
+        query.column(Column::new("bar"));
+        query.distinct();
+
+        let (sql, _) = Postgres::build(query);
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_from() {
+        let expected_sql =
+            "SELECT \"foo\".*, \"bar\".\"a\" FROM \"foo\", (SELECT \"a\" FROM \"baz\") AS \"bar\"";
+
+        let mut query = Select::default();
+        query.and_from("foo");
+
+        query.and_from(
+            Table::from({
+                let mut query = Select::from_table("baz");
+                query.column("a");
+                query
+            })
+            .alias("bar"),
+        );
+
+        query.value(Table::from("foo").asterisk());
+        query.column(("bar", "a"));
+
+        let (sql, _) = Postgres::build(query);
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_like_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
+            vec!["%foo%"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").like("%foo%"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_not_like_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
+            vec!["%foo%"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").not_like("%foo%"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_begins_with_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
+            vec!["%foo"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").like("%foo"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_not_begins_with_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
+            vec!["%foo"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").not_like("%foo"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_ends_with_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
+            vec!["foo%"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").like("foo%"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_not_ends_with_cast_to_string() {
+        let expected = expected_values(
+            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
+            vec!["foo%"],
+        );
+
+        let mut query = Select::from_table("test");
+        query.so_that(Column::from("jsonField").not_like("foo%"));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_default_insert() {
+        let mut insert = Insert::single_into("foo");
+        insert.value("foo", "bar");
+        insert.value("baz", default_value());
+
+        let (sql, _) = Postgres::build(insert);
+
+        assert_eq!(
+            "INSERT INTO \"foo\" (\"foo\",\"baz\") VALUES ($1,DEFAULT)",
+            sql
+        );
+    }
+
+    #[test]
+    fn join_is_inserted_positionally() {
+        let joined_table = Table::from("User").left_join(
+            Table::from("Post")
+                .alias("p")
+                .on(("p", "userId").equals(Column::from(("User", "id")))),
+        );
+
+        let mut q = Select::from_table(joined_table);
+        q.and_from("Toto");
+
+        let (sql, _) = Postgres::build(q);
+
+        assert_eq!("SELECT \"User\".*, \"Toto\".* FROM \"User\" LEFT JOIN \"Post\" AS \"p\" ON \"p\".\"userId\" = \"User\".\"id\", \"Toto\"", sql);
+    }
+
+    #[test]
+    fn test_json_build_object_raw_value() {
+        let mut select = Select::default();
+        select.value(json_build_object([("id", raw("1"))]));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(r#"SELECT json_build_object('id', 1)"#, sql);
+    }
+
+    #[test]
+    fn test_json_build_object_column() {
+        let mut select = Select::from_table("User");
+        select.value(json_build_object([("name", Column::from("name"))]));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT json_build_object('name', "name") FROM "User""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_cte() {
+        let mut insert = Insert::single_into("User");
+        insert.value("name", "Musti");
+
+        let mut insert = insert.build();
+        insert.returning(["id", "name"]);
+
+        let mut select = Select::from_table("public_user");
+        select.with(CommonTableExpression::new("public_user", insert));
+        select.columns(["id", "name"]);
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"WITH "public_user" AS (INSERT INTO "User" ("name") VALUES ($1) RETURNING "id", "name") SELECT "id", "name" FROM "public_user""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_debug_sql_inlines_scalars() {
+        let mut query = Select::from_table("users");
+        query.so_that(
+            "name"
+                .equals("it's")
+                .and("active".equals(true))
+                .and("deleted_at".equals(Value::Null))
+                .and("score".greater_than(1.5)),
+        );
+
+        let sql = Postgres::debug_sql(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("name" = 'it''s' AND "active" = true AND "deleted_at" = NULL AND "score" > 1.5)"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_debug_sql_escapes_backslashes() {
+        let mut query = Select::from_table("users");
+        query.so_that("path".equals(r"C:\temp\'x'"));
+
+        let sql = Postgres::debug_sql(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE "path" = E'C:\\temp\\''x'''"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_debug_sql_arrays_and_objects() {
+        let mut query = Select::from_table("users");
+        query.so_that(
+            "tags"
+                .equals(SqlValue::Array(vec![
+                    SqlValue::Text("a".to_string()),
+                    SqlValue::Text("b'c".to_string()),
+                ]))
+                .and("empty".equals(SqlValue::Array(Vec::new())))
+                .and("data".array_contains(serde_json::json!({"key": "it's"})))
+                .and("list".array_contains(serde_json::json!(["a"]))),
+        );
+
+        let sql = Postgres::debug_sql(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("tags" = ARRAY['a', 'b''c'] AND "empty" = '{}' AND "data" @> '{"key":"it''s"}'::jsonb AND "list" @> '["a"]'::jsonb)"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_debug_sql_json_path() {
+        let mut select = Select::from_table("users");
+        select.value(json_extract(
+            Column::from("data"),
+            JsonPath::array(["a", "b"]),
+            false,
+        ));
+
+        let sql = Postgres::debug_sql(select);
+
+        assert_eq!(
+            r##"SELECT ("data"#>ARRAY['a', 'b']::text[])::jsonb FROM "users""##,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_identifier_quotes_are_escaped() {
+        let mut select = Select::from_table(r#"users"; DROP TABLE "users"#);
+        select.column(Column::from(r#"na"me"#).alias(r#"x" FROM secrets --"#));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "na""me" AS "x"" FROM secrets --" FROM "users""; DROP TABLE ""users""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_table_alias_quotes_are_escaped() {
+        let select = Select::from_table(Table::from("users").alias(r#"u".* FROM secrets --"#));
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "u"".* FROM secrets --".* FROM "users" AS "u"".* FROM secrets --""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_cte_name_quotes_are_escaped() {
+        let mut inner = Select::from_table("users");
+        inner.column("id");
+
+        let mut select = Select::from_table(r#"cte"); DROP TABLE "users"#);
+        select.with(CommonTableExpression::new(
+            r#"cte"); DROP TABLE "users"#,
+            inner,
+        ));
+        select.column("id");
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"WITH "cte""); DROP TABLE ""users" AS (SELECT "id" FROM "users") SELECT "id" FROM "cte""); DROP TABLE ""users""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_json_build_object_identifier_quotes_are_escaped() {
+        let mut select = Select::from_table("User");
+        select.value(json_build_object([("name", Column::from(r#"na"me"#))]).alias(r#"obj" --"#));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT json_build_object('name', "na""me") AS "obj"" --" FROM "User""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_json_build_object_key_quotes_are_escaped() {
+        let mut select = Select::from_table("User");
+        select.value(json_build_object([(
+            "it's', (SELECT password FROM secrets), '",
+            Column::from("name"),
+        )]));
+
+        let (sql, params) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT json_build_object('it''s'', (SELECT password FROM secrets), ''', "name") FROM "User""#,
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_json_build_object_over_argument_limit_is_chunked() {
+        let names: Vec<String> = (0..120).map(|i| format!("k{i}")).collect();
+
+        let mut select = Select::default();
+        select.value(json_build_object(
+            names.iter().map(|name| (name.as_str(), raw("1"))),
+        ));
+
+        let (sql, _) = Postgres::build(select);
+
+        let chunk = |range: std::ops::Range<usize>| {
+            let pairs: Vec<String> = range.map(|i| format!("'k{i}', 1")).collect();
+            format!("jsonb_build_object({})", pairs.join(","))
+        };
+
+        let expected = format!(
+            "SELECT ({} || {} || {})",
+            chunk(0..50),
+            chunk(50..100),
+            chunk(100..120)
+        );
+
+        assert_eq!(expected, sql);
+    }
+
+    #[test]
+    fn test_json_build_object_at_argument_limit_is_not_chunked() {
+        let names: Vec<String> = (0..50).map(|i| format!("k{i}")).collect();
+
+        let mut select = Select::default();
+        select.value(json_build_object(
+            names.iter().map(|name| (name.as_str(), raw("1"))),
+        ));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert!(sql.starts_with("SELECT json_build_object('k0', 1,"));
+        assert!(!sql.contains("jsonb_build_object"));
+    }
+
+    #[test]
+    fn test_try_build_parameter_overflow() {
+        let mut insert = Insert::multi_into("users", ["a", "b"]);
+
+        for i in 0..32768 {
+            insert.values((i, i));
+        }
+
+        let error = Postgres::try_build(insert).unwrap_err();
+
+        assert_eq!(
+            "The query has 65536 parameters, more than the maximum of 65535.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_multi_row_insert_split_under_parameter_limit() {
+        let mut insert = Insert::multi_into("users", ["a", "b"]);
+
+        for i in 0..32768 {
+            insert.values((i, i));
+        }
+
+        let inserts = insert.split(Postgres::MAX_PARAMETERS).unwrap();
+        assert_eq!(2, inserts.len());
+
+        let (_, params) = Postgres::try_build(inserts[0].clone()).unwrap();
+        assert_eq!(65534, params.len());
+
+        let (sql, params) = Postgres::try_build(inserts[1].clone()).unwrap();
+        assert_eq!(r#"INSERT INTO "users" ("a","b") VALUES ($1,$2)"#, sql);
+        assert_eq!(vec![Value::from(32767), Value::from(32767)], params);
+    }
+
+    #[test]
+    fn test_in_list_default_rendering() {
+        let mut query = Select::from_table("users");
+        query.so_that("id".in_selection(Row::from(vec![1, 2])));
+
+        let (sql, params) = Postgres::build(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE "id" IN ($1,$2)"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from(2)], params);
+    }
+
+    #[test]
+    fn test_in_list_as_array_is_stable_across_lengths() {
+        let render = |ids: Vec<i32>| {
+            let mut query = Select::from_table("users");
+            query.so_that("id".in_selection(Row::from(ids)));
+
+            Postgres::new().in_lists_as_arrays().render(query)
+        };
+
+        let (short_sql, short_params) = render(vec![1]);
+        let (long_sql, long_params) = render(vec![1, 2, 3, 4]);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE "id" = ANY($1)"#,
+            short_sql
+        );
+        assert_eq!(short_sql, long_sql);
+        assert_eq!(vec![Value::from(vec![1])], short_params);
+        assert_eq!(vec![Value::from(vec![1, 2, 3, 4])], long_params);
+    }
+
+    #[test]
+    fn test_not_in_list_as_array() {
+        let mut query = Select::from_table("users");
+        query.so_that(
+            Row::from(vec![Column::from("id")])
+                .not_in_selection(Values::new(vec![Row::from((1,)), Row::from((2,))])),
+        );
+
+        let (sql, params) = Postgres::new().in_lists_as_arrays().render(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE NOT ("id" = ANY($1))"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(vec![1, 2])], params);
+    }
+
+    #[test]
+    fn test_in_list_as_array_falls_back_with_non_parameters() {
+        let mut query = Select::from_table("users");
+        query.so_that("id".in_selection(Row::from(vec![
+            Expression::from(1),
+            Column::from("other_id").into(),
+        ])));
+
+        let (sql, params) = Postgres::new().in_lists_as_arrays().render(query);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE "id" IN ($1,"other_id")"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_select_comment_and_tags() {
+        let mut select = Select::from_table("users");
+        select.comment("user listing");
+        select.tag(
+            "traceparent",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        );
+        select.tag("operation", "it's */ --");
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" /* user listing */ /*operation='it%27s%20%2A%2F%20--',traceparent='00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01'*/"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_insert_comment() {
+        let mut insert = Insert::single_into("users");
+        insert.value("foo", 10);
+
+        let mut insert = insert.build();
+        insert.returning(["id"]);
+        insert.comment("nested /* comment */");
+
+        let (sql, _) = Postgres::build(insert);
+
+        assert_eq!(
+            r#"INSERT INTO "users" ("foo") VALUES ($1) RETURNING "id" /* nested / * comment * / */"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_update_tags() {
+        let mut update = Update::table("users");
+        update.set("foo", 1);
+        update.tag("route", "/users");
 
-        // NOTE: Pg is strongly typed, LIKE comparisons are only between strings.
-        // to avoid problems with types without implicit casting we explicitly cast to text
-        if need_cast {
-            self.write("::text");
-        }
+        let (sql, _) = Postgres::build(update);
 
-        self.write(" NOT LIKE ");
-        self.visit_expression(right);
+        assert_eq!(r#"UPDATE "users" SET "foo" = $1 /*route='%2Fusers'*/"#, sql);
     }
 
-    fn visit_ordering(&mut self, ordering: Ordering<'a>) {
-        let len = ordering.0.len();
+    #[test]
+    fn test_upsert_does_not_render_update_comment() {
+        let mut update = Update::table("users");
+        update.set("foo", 3);
+        update.comment("ignored");
 
-        for (i, (value, ordering)) in ordering.0.into_iter().enumerate() {
-            let direction = ordering.map(|dir| match dir {
-                Order::Asc => " ASC",
-                Order::Desc => " DESC",
-                Order::AscNullsFirst => " ASC NULLS FIRST",
-                Order::AscNullsLast => " ASC NULLS LAST",
-                Order::DescNullsFirst => " DESC NULLS FIRST",
-                Order::DescNullsLast => " DESC NULLS LAST",
-            });
+        let mut insert = Insert::single_into("users");
+        insert.value("foo", 10);
 
-            self.visit_expression(value);
-            self.write(direction.unwrap_or(""));
+        let mut insert = insert.build();
+        insert.on_conflict(OnConflict::Update(update, Vec::from(["foo".into()])));
+        insert.comment("upsert");
 
-            if i < (len - 1) {
-                self.write(", ");
-            }
-        }
+        let (sql, _) = Postgres::build(insert);
+
+        assert_eq!(
+            r#"INSERT INTO "users" ("foo") VALUES ($1) ON CONFLICT ("foo") DO UPDATE SET "foo" = $2 /* upsert */"#,
+            sql
+        );
     }
 
-    fn visit_concat(&mut self, concat: Concat<'a>) {
-        let len = concat.exprs.len();
+    #[test]
+    fn test_typed_parameters() {
+        let mut insert = Insert::single_into("files");
+        insert.value(
+            "id",
+            SqlValue::Uuid(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8),
+        );
+        insert.value("size", SqlValue::Int32(10));
+        insert.value("data", SqlValue::Bytes(vec![0xde, 0xad]));
+        insert.value("parent", SqlValue::Null.with_type(SqlType::Uuid));
 
-        self.surround_with("(", ")", |s| {
-            for (i, expr) in concat.exprs.into_iter().enumerate() {
-                s.visit_expression(expr);
+        let (sql, params) = Postgres::build(insert);
 
-                if i < (len - 1) {
-                    s.write(" || ");
-                }
-            }
-        });
-    }
+        assert_eq!(
+            r#"INSERT INTO "files" ("id","size","data","parent") VALUES ($1,$2,$3,$4)"#,
+            sql
+        );
 
-    fn visit_to_jsonb(&mut self, to_jsonb: ToJsonb<'a>) {
-        self.write("to_jsonb(");
-        self.visit_table(to_jsonb.table, false);
-        self.write(".*)");
+        assert_eq!(
+            vec![
+                SqlValue::Uuid(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8),
+                SqlValue::Int32(10),
+                SqlValue::Bytes(vec![0xde, 0xad]),
+                SqlValue::Typed(Box::new(SqlValue::Null), SqlType::Uuid),
+            ],
+            params
+        );
     }
 
-    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'a>) {
-        let values_length = json_build_object.values.len();
-        self.write("json_build_object(");
+    #[test]
+    fn test_json_values_convert_to_typed_parameters() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".equals(1)
+                .and("b".equals(1.5))
+                .and("c".equals(u64::MAX))
+                .and("d".equals(serde_json::json!({"x": 1}))),
+        );
 
-        for (i, (name, expression)) in json_build_object.values.into_iter().enumerate() {
-            self.surround_with("'", "'", |renderer| {
-                renderer.write(&name);
-            });
+        let (_, params) = Postgres::build(select);
+
+        assert_eq!(
+            vec![
+                SqlValue::Int64(1),
+                SqlValue::Double(1.5),
+                SqlValue::Numeric(u64::MAX.to_string()),
+                SqlValue::Json(serde_json::json!({"x": 1})),
+            ],
+            params
+        );
+    }
 
-            self.write(", ");
-            self.visit_expression(expression);
+    #[test]
+    fn test_debug_sql_typed_literals() {
+        let mut insert = Insert::single_into("files");
+        insert.value(
+            "id",
+            SqlValue::Uuid(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8),
+        );
+        insert.value("data", SqlValue::Bytes(vec![0xde, 0xad]));
+        insert.value("price", SqlValue::Numeric("12.50".to_string()));
+        insert.value(
+            "created_at",
+            SqlValue::TimestampTz("2023-11-07T10:00:00Z".to_string()),
+        );
+        insert.value("ratio", SqlValue::Double(f64::NAN));
+        insert.value("parent", SqlValue::Null.with_type(SqlType::Uuid));
 
-            if i < (values_length - 1) {
-                self.write(",");
-            }
-        }
+        let sql = Postgres::debug_sql(insert);
 
-        self.write(")");
+        assert_eq!(
+            r#"INSERT INTO "files" ("id","data","price","created_at","ratio","parent") VALUES ('67e55044-10b1-426f-9247-bb680e5fe0c8'::uuid,E'\\xdead'::bytea,'12.50'::numeric,'2023-11-07T10:00:00Z'::timestamptz,'NaN'::float8,(NULL)::uuid)"#,
+            sql
+        );
     }
 
-    fn visit_json_agg(&mut self, json_agg: JsonAgg<'a>) {
-        self.write("json_agg(");
+    #[test]
+    fn test_sql_value_types() {
+        assert_eq!(None, SqlValue::Null.sql_type());
+        assert_eq!(
+            Some(SqlType::BigInt),
+            SqlValue::from(Value::from(1)).sql_type()
+        );
+        assert_eq!(
+            Some(SqlType::Array(Box::new(SqlType::Text))),
+            SqlValue::Array(vec![SqlValue::Null, SqlValue::Text("a".to_string())]).sql_type()
+        );
+        assert_eq!(
+            None,
+            SqlValue::from(serde_json::json!([null, "a"])).sql_type()
+        );
+        assert_eq!(None, SqlValue::from(serde_json::json!({"a": 1})).sql_type());
+        assert_eq!(
+            Some(SqlType::Json),
+            SqlValue::Text("{}".to_string())
+                .with_type(SqlType::Text)
+                .with_type(SqlType::Json)
+                .sql_type()
+        );
+    }
 
-        if json_agg.distinct {
-            self.write("DISTINCT ");
-        }
+    #[test]
+    fn test_parameter_types_for_json_comparisons() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Expression::from(json_extract(
+                Column::from("data"),
+                JsonPath::array(["tags"]),
+                false,
+            ))
+            .array_contains(Value::from(vec!["admin"]))
+            .and(
+                Expression::from(json_extract(
+                    Column::from("data"),
+                    JsonPath::array(["name"]),
+                    true,
+                ))
+                .equals("Musti"),
+            )
+            .and("data".array_contains(Value::from(vec!["admin"]))),
+        );
 
-        self.visit_expression(json_agg.expression);
+        let (sql, params, types) = Postgres::build_with_types(select);
 
-        if let Some(ordering) = json_agg.order_by {
-            self.write(" ORDER BY ");
-            self.visit_ordering(ordering);
-        }
+        assert_eq!(
+            r##"SELECT "users".* FROM "users" WHERE (("data"#>ARRAY[$1]::text[])::jsonb @> $2 AND ("data"#>>ARRAY[$3]::text[]) = $4 AND "data" @> $5)"##,
+            sql
+        );
 
-        self.write(")");
+        assert_eq!(5, params.len());
+
+        assert_eq!(
+            vec![
+                Some(SqlType::Text),
+                Some(SqlType::Jsonb),
+                Some(SqlType::Text),
+                Some(SqlType::Text),
+                None,
+            ],
+            types
+        );
     }
 
-    fn visit_encode(&mut self, encode: Encode<'a>) {
-        self.write("encode(");
-        self.visit_expression(encode.expression);
-        self.write(", ");
+    #[test]
+    fn test_declared_parameter_type_wins() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Expression::from(json_extract(
+                Column::from("data"),
+                JsonPath::array(["id"]),
+                false,
+            ))
+            .equals(SqlValue::Text("1".to_string()).with_type(SqlType::Json)),
+        );
 
-        match encode.format {
-            EncodeFormat::Base64 => self.write("'base64'"),
-            EncodeFormat::Escape => self.write("'escape'"),
-            EncodeFormat::Hex => self.write("'hex'"),
-        }
+        let (_, _, types) = Postgres::build_with_types(select);
 
-        self.write(")");
+        assert_eq!(vec![Some(SqlType::Text), Some(SqlType::Json)], types);
     }
 
-    fn visit_join_data(&mut self, data: JoinData<'a>) {
-        if data.lateral {
-            self.write(" LATERAL ");
-        }
+    #[test]
+    fn test_parameter_types_for_in_list_as_array() {
+        let mut select = Select::from_table("users");
+        select.so_that("id".in_selection(Row::from(vec![1, 2])));
 
-        self.visit_table(data.table, true);
-        self.write(" ON ");
-        self.visit_conditions(data.conditions)
+        let (_, _, types) = Postgres::new()
+            .in_lists_as_arrays()
+            .render_with_types(select);
+
+        assert_eq!(vec![Some(SqlType::Array(Box::new(SqlType::BigInt)))], types);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{ast::json_build_object, renderer::*};
+    #[test]
+    fn test_deduplicate_json_path_parameters() {
+        let mut select = Select::from_table("users");
+        select.value(Expression::from(json_extract(
+            Column::from("data"),
+            JsonPath::array(["a", "b"]),
+            false,
+        )));
+        select.value(Expression::from(json_extract(
+            Column::from("other"),
+            JsonPath::array(["a"]),
+            false,
+        )));
+
+        let (sql, params) = Postgres::new().deduplicate_parameters().render(select);
 
-    fn expected_values<T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value>)
-    where
-        T: Into<Value>,
-    {
-        (
-            String::from(sql),
-            params.into_iter().map(|p| p.into()).collect(),
-        )
+        assert_eq!(
+            r##"SELECT ("data"#>ARRAY[$1, $2]::text[])::jsonb, ("other"#>ARRAY[$1]::text[])::jsonb FROM "users""##,
+            sql
+        );
+        assert_eq!(vec![Value::from("a"), Value::from("b")], params);
     }
 
-    fn default_params(mut additional: Vec<Value>) -> Vec<Value> {
-        let mut result = Vec::new();
+    #[test]
+    fn test_deduplicate_keeps_values_of_different_types_apart() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".equals(1)
+                .and("b".equals(SqlValue::Int32(1)))
+                .and(
+                    Expression::from(json_extract(
+                        Column::from("data"),
+                        JsonPath::array(["x"]),
+                        false,
+                    ))
+                    .equals("x"),
+                )
+                .and("c".equals("x"))
+                .and("d".equals(1)),
+        );
 
-        for param in additional.drain(0..) {
-            result.push(param)
-        }
+        let (sql, params, types) = Postgres::new()
+            .deduplicate_parameters()
+            .render_with_types(select);
 
-        result
+        assert_eq!(
+            r##"SELECT "users".* FROM "users" WHERE ("a" = $1 AND "b" = $2 AND ("data"#>ARRAY[$3]::text[])::jsonb = $4 AND "c" = $3 AND "d" = $1)"##,
+            sql
+        );
+        assert_eq!(4, params.len());
+        assert_eq!(
+            vec![
+                Some(SqlType::BigInt),
+                Some(SqlType::Integer),
+                Some(SqlType::Text),
+                Some(SqlType::Jsonb),
+            ],
+            types
+        );
     }
 
     #[test]
-    fn test_single_row_insert_default_values() {
-        let query = Insert::single_into("users");
-        let (sql, params) = Postgres::build(query);
+    fn test_parameters_are_not_deduplicated_by_default() {
+        let mut select = Select::from_table("users");
+        select.so_that("a".equals(1).and("b".equals(1)));
 
-        assert_eq!("INSERT INTO \"users\" DEFAULT VALUES", sql);
-        assert_eq!(default_params(vec![]), params);
+        let (sql, params) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a" = $1 AND "b" = $2)"#,
+            sql
+        );
+        assert_eq!(2, params.len());
     }
 
     #[test]
-    fn test_single_row_insert() {
-        let expected = expected_values("INSERT INTO \"users\" (\"foo\") VALUES ($1)", vec![10]);
+    fn test_placeholders_between_parameters() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".equals(1)
+                .and("b".equals(placeholder("b")))
+                .and("c".equals(2))
+                .and("d".equals(placeholder("b"))),
+        );
 
-        let mut insert = Insert::single_into("users");
-        insert.value("foo", 10);
+        let template = Postgres::new().render_template(select);
 
-        let (sql, params) = Postgres::build(insert);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a" = $1 AND "b" = $2 AND "c" = $3 AND "d" = $2)"#,
+            template.sql
+        );
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        let params = template.bind([("b", SqlValue::Text("x".into()))]).unwrap();
+
+        assert_eq!(
+            vec![Value::from(1), Value::from("x"), Value::from(2)],
+            params
+        );
     }
 
     #[test]
-    #[cfg(feature = "postgresql")]
-    fn test_returning_insert() {
-        let expected = expected_values(
-            "INSERT INTO \"users\" (\"foo\") VALUES ($1) RETURNING \"foo\"",
-            vec![10],
+    fn test_template_keeps_placeholder_types() {
+        let select = |length: Expression<'static>| {
+            let mut select = Select::from_table("users");
+            select.value(left(Column::from("name"), length));
+            select.so_that("id".equals(1));
+            select
+        };
+
+        let template = Postgres::new().render_template(select(placeholder("length")));
+        let (_, _, types) = Postgres::build_with_types(select(Expression::from(3)));
+
+        assert_eq!(types, template.parameter_types);
+        assert_eq!(
+            vec![Some(SqlType::Integer), Some(SqlType::BigInt)],
+            template.parameter_types
         );
+    }
 
-        let mut query = Insert::single_into("users");
-        query.value("foo", 10);
+    #[test]
+    fn test_template_bind_errors() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".equals(placeholder("a"))
+                .and("b".equals(placeholder("b"))),
+        );
 
-        let mut query = query.build();
-        query.returning(vec!["foo"]);
+        let template = Postgres::new().render_template(select);
 
-        let (sql, params) = Postgres::build(query);
+        let error = template.bind([("a", SqlValue::Null)]).unwrap_err();
+        assert_eq!("No value bound to the placeholders: b.", error.to_string());
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        let error = template.bind([("c", SqlValue::Null)]).unwrap_err();
+        assert_eq!("The query has no placeholder named `c`.", error.to_string());
     }
 
     #[test]
-    #[cfg(feature = "postgresql")]
-    fn test_insert_on_conflict_update() {
-        let expected = expected_values(
-            "INSERT INTO \"users\" (\"foo\") VALUES ($1) ON CONFLICT (\"foo\") DO UPDATE SET \"foo\" = $2 WHERE \"users\".\"foo\" = $3 RETURNING \"foo\"",
-            vec![10, 3, 1],
-        );
+    fn test_placeholder_in_debug_sql() {
+        let mut select = Select::from_table("users");
+        select.so_that("id".equals(placeholder("id")));
+        select.and_where("name".equals(placeholder("my 'var")));
 
-        let mut update = Update::table("users");
-        update.set("foo", 3);
-        update.so_that(("users", "foo").equals(1));
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("id" = :'id' AND "name" = :'my ''var')"#,
+            Postgres::debug_sql(select)
+        );
+    }
 
-        let mut insert = Insert::single_into("users");
-        insert.value("foo", 10);
+    #[test]
+    fn test_normalized_sql_of_insert() {
+        let mut insert = Insert::multi_into("users", ["id", "name"]);
+        insert.values((1, "Musti"));
+        insert.values((2, "Naukio"));
 
-        let mut insert = insert.build();
-        insert.returning(vec!["foo"]);
-        insert.on_conflict(OnConflict::Update(update, Vec::from(["foo".into()])));
+        assert_eq!(
+            r#"INSERT INTO "users" ("id","name") VALUES (?,?), (?,?)"#,
+            Postgres::normalized_sql(insert)
+        );
+    }
 
-        let (sql, params) = Postgres::build(insert);
+    #[test]
+    fn test_normalized_sql_keeps_non_parameter_in_lists() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "id".in_selection(Row::from((Column::from("a"), 1)))
+                .and("parent".not_in_selection(Row::from(vec![1, 2]))),
+        );
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("id" IN ("a",?) AND "parent" NOT IN (...))"#,
+            Postgres::normalized_sql(select)
+        );
     }
 
     #[test]
-    fn test_multi_row_insert() {
-        let expected = expected_values(
-            "INSERT INTO \"users\" (\"foo\") VALUES ($1), ($2)",
-            vec![10, 11],
+    fn test_fingerprint_ignores_the_number_of_tuples() {
+        let fingerprint = |rows: Vec<(i64, i64)>| {
+            let mut select = Select::from_table("users");
+            select.so_that(
+                Row::from((Column::from("a"), Column::from("b")))
+                    .in_selection(Values::new(rows.into_iter().map(Row::from).collect())),
+            );
+            select
+                .and_where("c".in_selection(Row::from(vec![Row::from((1, 2)), Row::from((3, 4))])));
+
+            Query::from(select).fingerprint()
+        };
+
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Row::from((Column::from("a"), Column::from("b")))
+                .not_in_selection(Values::new(vec![Row::from((1, 2)), Row::from((3, 4))])),
         );
 
-        let mut insert = Insert::multi_into("users", vec!["foo"]);
-        insert.values(vec![10]);
-        insert.values(vec![11]);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a","b") NOT IN (...)"#,
+            Postgres::normalized_sql(select)
+        );
+        assert_eq!(
+            fingerprint(vec![(1, 2)]),
+            fingerprint(vec![(1, 2), (3, 4), (5, 6)])
+        );
+    }
 
-        let (sql, params) = Postgres::build(insert);
+    #[test]
+    fn test_fingerprint_depends_on_the_shape() {
+        let fingerprint = |column: &'static str, value: i64| {
+            let mut select = Select::from_table("users");
+            select.so_that(column.equals(value));
+            select.comment(format!("run {value}"));
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+            Query::from(select).fingerprint()
+        };
+
+        assert_eq!(fingerprint("id", 1), fingerprint("id", 2));
+        assert_ne!(fingerprint("id", 1), fingerprint("parent_id", 1));
+        assert_eq!(3017706433570587637, fingerprint("id", 1));
     }
 
     #[test]
-    fn test_limit_and_offset_when_both_are_set() {
-        let expected = expected_values(
-            "SELECT \"users\".* FROM \"users\" LIMIT $1 OFFSET $2",
-            vec![10_i64, 2_i64],
-        );
+    fn test_raw_with_params_escaped_question_mark() {
+        let mut select = Select::from_table("users");
+        select.so_that(ConditionTree::single(raw_with_params(
+            "data ?? ? AND ? > 1",
+            ["admin", "score"],
+        )));
 
-        let mut query = Select::from_table("users");
-        query.limit(10);
-        query.offset(2);
+        let (sql, params) = Postgres::build(select);
 
-        let (sql, params) = Postgres::build(query);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE data ? $1 AND $2 > 1"#,
+            sql
+        );
+        assert_eq!(vec![Value::from("admin"), Value::from("score")], params);
+    }
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+    #[test]
+    #[should_panic(expected = "The raw fragment has 2 placeholders for 1 expressions.")]
+    fn test_raw_with_params_placeholder_count_mismatch() {
+        raw_with_params("f(?, ?)", [1]);
     }
 
     #[test]
-    fn test_limit_and_offset_when_only_offset_is_set() {
-        let expected = expected_values("SELECT \"users\".* FROM \"users\" OFFSET $1", vec![10_i64]);
+    fn test_strict_render_rejects_raw() {
+        let mut select = Select::from_table("users");
+        select.value(raw("now()"));
 
-        let mut query = Select::from_table("users");
-        query.offset(10);
+        let mut sql = String::new();
+        let mut params = Vec::new();
 
-        let (sql, params) = Postgres::build(query);
+        let error = Postgres::new()
+            .strict()
+            .try_render_into(select, &mut sql, &mut params)
+            .unwrap_err();
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            "Raw SQL is not allowed in strict mode, found: `now()`.",
+            error.to_string()
+        );
     }
 
     #[test]
-    fn test_limit_and_offset_when_only_limit_is_set() {
-        let expected = expected_values("SELECT \"users\".* FROM \"users\" LIMIT $1", vec![10_i64]);
+    fn test_raw_fragments_are_not_recorded_by_default() {
+        let mut select = Select::from_table("users");
+        select.value(raw("now()"));
 
-        let mut query = Select::from_table("users");
-        query.limit(10);
+        let mut postgres = Postgres::new();
+        Postgres::visit_query(&mut postgres, select.into());
 
-        let (sql, params) = Postgres::build(query);
+        assert!(postgres.raw_fragments.is_empty());
+    }
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+    #[test]
+    fn test_strict_render_without_raw() {
+        let mut select = Select::from_table("users");
+        select.so_that("id".equals(1));
+
+        let (sql, _) = Postgres::new().strict().try_render(select).unwrap();
+
+        assert_eq!(r#"SELECT "users".* FROM "users" WHERE "id" = $1"#, sql);
     }
 
     #[test]
-    fn test_distinct() {
-        let expected_sql = "SELECT DISTINCT \"bar\" FROM \"test\"";
+    fn test_raw_fragments_in_subqueries() {
+        let mut inner = Select::from_table("posts");
+        inner.value(raw("count(*)"));
 
-        let mut query = Select::from_table("test");
-        query.column(Column::new("bar"));
-        query.distinct();
+        let mut select = Select::from_table("users");
+        select.value(Expression::from(inner).alias("posts"));
+        select.so_that("name".compare_raw("ILIKE", "a%"));
 
-        let (sql, _) = Postgres::build(query);
+        assert_eq!(vec!["count(*)", "ILIKE"], Postgres::raw_fragments(select));
+    }
 
-        assert_eq!(expected_sql, sql);
+    #[test]
+    fn test_pretty_ctes_and_joins() {
+        let mut cte = Select::from_table("users");
+        cte.column("id");
+        cte.so_that("active".equals(true));
+
+        let join = Table::from("posts")
+            .alias("p")
+            .on(("p", "user_id").equals(Column::from(("active_users", "id"))));
+
+        let mut select = Select::from_table("active_users");
+        select.with(CommonTableExpression::new("active_users", cte));
+        select.left_join(join);
+        select.column(("p", "title"));
+        select.order_by("id");
+
+        let (sql, _) = Postgres::new().pretty().render(select);
+
+        let expected = r#"WITH "active_users" AS (
+  SELECT "id"
+  FROM "users"
+  WHERE "active" = $1
+)
+SELECT "p"."title"
+FROM "active_users"
+LEFT JOIN "posts" AS "p"
+  ON "p"."user_id" = "active_users"."id"
+ORDER BY "id""#;
+
+        assert_eq!(expected, sql);
     }
 
     #[test]
-    fn test_distinct_with_subquery() {
-        let expected_sql = "SELECT DISTINCT (SELECT $1 FROM \"test2\"), \"bar\" FROM \"test\"";
+    fn test_pretty_insert() {
+        let mut insert = Insert::multi_into("users", ["id"]);
+        insert.values((1,));
+        insert.values((2,));
 
-        let mut query = Select::from_table("test");
+        let mut insert = insert.build();
+        insert.on_conflict(OnConflict::DoNothing);
+        insert.returning(["id"]);
 
-        query.value({
-            let mut query = Select::from_table("test2");
-            query.value(1);
+        let (sql, _) = Postgres::new().pretty().render(insert);
 
-            query
-        });
+        let expected = r#"INSERT INTO "users" ("id")
+VALUES ($1), ($2)
+ON CONFLICT DO NOTHING
+RETURNING "id""#;
 
-        query.column(Column::new("bar"));
-        query.distinct();
+        assert_eq!(expected, sql);
+    }
 
-        let (sql, _) = Postgres::build(query);
+    #[test]
+    fn test_render_into_reuses_buffers() {
+        let mut sql = String::with_capacity(8192);
+        let mut params = Vec::with_capacity(256);
 
-        assert_eq!(expected_sql, sql);
+        for _ in 0..2 {
+            let mut insert = Insert::multi_into("users", ["id"]);
+
+            for i in 0..100 {
+                insert.values((i,));
+            }
+
+            Postgres::new().render_into(insert, &mut sql, &mut params);
+
+            assert!(sql.starts_with(r#"INSERT INTO "users" ("id") VALUES ($1), ($2)"#));
+            assert_eq!(100, params.len());
+            assert_eq!(8192, sql.capacity());
+            assert_eq!(256, params.capacity());
+        }
     }
 
     #[test]
-    fn test_from() {
-        let expected_sql =
-            "SELECT \"foo\".*, \"bar\".\"a\" FROM \"foo\", (SELECT \"a\" FROM \"baz\") AS \"bar\"";
+    fn test_case_insensitive_and_regex_matching() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Column::from("name")
+                .ilike("%musti%")
+                .and(Column::from("name").not_ilike("%naukio%"))
+                .and(Column::from("email").regex_match("^a"))
+                .and(Column::from("email").not_regex_match("^b"))
+                .and(Column::from("email").iregex_match("^c"))
+                .and(Column::from("email").not_iregex_match("^d")),
+        );
 
-        let mut query = Select::default();
-        query.and_from("foo");
+        let (sql, params) = Postgres::build(select);
 
-        query.and_from(
-            Table::from({
-                let mut query = Select::from_table("baz");
-                query.column("a");
-                query
-            })
-            .alias("bar"),
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("name"::text ILIKE $1 AND "name"::text NOT ILIKE $2 AND "email"::text ~ $3 AND "email"::text !~ $4 AND "email"::text ~* $5 AND "email"::text !~* $6)"#,
+            sql
         );
+        assert_eq!(6, params.len());
+    }
 
-        query.value(Table::from("foo").asterisk());
-        query.column(("bar", "a"));
+    #[test]
+    fn test_similar_to() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Column::from("code")
+                .similar_to("(a|b)%")
+                .or(Expression::from("x").not_similar_to("_")),
+        );
 
-        let (sql, _) = Postgres::build(query);
-        assert_eq!(expected_sql, sql);
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("code"::text SIMILAR TO $1 OR $2 NOT SIMILAR TO $3)"#,
+            sql
+        );
     }
 
     #[test]
-    fn test_like_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
-            vec!["%foo%"],
+    fn test_escaped_pattern_comparisons() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Column::from("a")
+                .has_suffix("_x")
+                .and(Column::from("b").has_substring(r"C:\temp")),
         );
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").like("%foo%"));
-
-        let (sql, params) = Postgres::build(query);
+        let (sql, params) = Postgres::build(select);
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a"::text LIKE $1 AND "b"::text LIKE $2)"#,
+            sql
+        );
+        assert_eq!(
+            vec![Value::from(r"%\_x"), Value::from(r"%C:\\temp%")],
+            params
+        );
     }
 
     #[test]
-    fn test_not_like_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
-            vec!["%foo%"],
+    fn test_distinct_from() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".is_distinct_from(1).and(
+                Row::from((Column::from("b"), Column::from("c")))
+                    .is_not_distinct_from(Row::from((2, 3))),
+            ),
         );
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").not_like("%foo%"));
-
-        let (sql, params) = Postgres::build(query);
+        let (sql, params) = Postgres::build(select);
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a" IS DISTINCT FROM $1 AND ("b","c") IS NOT DISTINCT FROM ($2,$3))"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from(2), Value::from(3)], params);
     }
 
     #[test]
-    fn test_begins_with_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
-            vec!["%foo"],
+    fn test_not_exists_in_conditions() {
+        let mut posts = Select::from_table("posts");
+        posts.value(raw("1"));
+        posts.so_that(("posts", "user_id").equals(Column::from(("users", "id"))));
+
+        let mut select = Select::from_table("users");
+        select.so_that(not_exists(posts).and("active".equals(true)));
+
+        let (sql, params) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE (NOT EXISTS (SELECT 1 FROM "posts" WHERE "posts"."user_id" = "users"."id") AND "active" = $1)"#,
+            sql
         );
+        assert_eq!(vec![Value::from(true)], params);
+    }
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").like("%foo"));
+    #[test]
+    fn test_quantified_comparison_over_array() {
+        let select = || {
+            let mut select = Select::from_table("users");
+            select.so_that(
+                "id".quantified(
+                    ComparisonOperator::Equals,
+                    Quantifier::Some,
+                    Column::from("friend_ids"),
+                )
+                .and("age".quantified(
+                    ComparisonOperator::LessThanOrEquals,
+                    Quantifier::Any,
+                    vec![18, 21],
+                )),
+            );
+            select
+        };
 
-        let (sql, params) = Postgres::build(query);
+        let (sql, params) = Postgres::build(select());
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("id" = SOME("friend_ids") AND "age" <= ANY($1))"#,
+            sql
+        );
+        assert_eq!(
+            vec![SqlValue::Array(vec![
+                SqlValue::Int64(18),
+                SqlValue::Int64(21)
+            ])],
+            params
+        );
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("id" = SOME("friend_ids") AND "age" <= ANY(ARRAY[18, 21]))"#,
+            Postgres::debug_sql(select())
+        );
     }
 
     #[test]
-    fn test_not_begins_with_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
-            vec!["%foo"],
+    fn test_function_call_quotes_names() {
+        let call = function_call("MyAgg\"(", [Column::from("a")])
+            .schema("Stats")
+            .distinct();
+
+        let mut select = Select::from_table("users");
+        select.value(call);
+        select.value(function_call("now", Vec::<Expression>::new()));
+        select.value(function_call("select", [Column::from("a")]).schema("user"));
+        select.value(function_call("Select", [Column::from("a")]));
+        select.value(function_call(
+            "ST_Distance",
+            [Column::from("a"), Column::from("b")],
+        ));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT Stats."MyAgg""("(DISTINCT "a"), now(), "user"."select"("a"), "Select"("a"), ST_Distance("a", "b") FROM "users""#,
+            sql
         );
+    }
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").not_like("%foo"));
+    #[test]
+    #[should_panic(expected = "The function name cannot be empty.")]
+    fn test_function_call_rejects_empty_names() {
+        function_call("", [Column::from("a")]);
+    }
 
-        let (sql, params) = Postgres::build(query);
+    #[test]
+    fn test_string_functions() {
+        let mut select = Select::from_table("users");
+        select.value(substring(
+            Column::from("name"),
+            2,
+            Some(Expression::from(3)),
+        ));
+        select.value(trim_characters(
+            Column::from("code"),
+            TrimSide::Leading,
+            Some(Expression::from("0")),
+        ));
+        select.value(rtrim(Column::from("name")));
+        select.value(trim(Column::from("name")));
+        select.value(length(Column::from("name")));
+        select.value(position("@", Column::from("email")));
+        select.value(split_part(Column::from("email"), "@", 2));
+        select.value(lpad(Column::from("code"), 5, "0"));
+        select.value(right(Column::from("name"), 1));
+
+        let (sql, params, types) = Postgres::build_with_types(select);
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"SELECT SUBSTRING("name" FROM $1 FOR $2), TRIM(LEADING $3 FROM "code"), TRIM(TRAILING FROM "name"), TRIM("name"), CHAR_LENGTH("name"), POSITION($4 IN "email"), SPLIT_PART("email", $5, $6), LPAD("code", $7, $8), RIGHT("name", $9) FROM "users""#,
+            sql
+        );
+        assert_eq!(9, params.len());
+        assert_eq!(Some(SqlType::Integer), types[0]);
+        assert_eq!(Some(SqlType::Integer), types[5]);
+        assert_eq!(Some(SqlType::Integer), types[8]);
     }
 
     #[test]
-    fn test_ends_with_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text LIKE $1"#,
-            vec!["foo%"],
+    fn test_date_time_functions() {
+        let mut select = Select::from_table("posts");
+        select.value(date_trunc(DateTimeField::Week, Column::from("created_at")).alias("week"));
+        select.value(extract(DateTimeField::Year, Column::from("created_at")));
+        select.value(at_time_zone(Column::from("created_at"), "Europe/Helsinki"));
+        select.value(
+            Expression::from(Column::from("created_at")) + interval(1, DateTimeField::Quarter),
+        );
+        select.so_that(
+            "created_at".greater_than(Expression::from(now()) - interval(7, DateTimeField::Day)),
         );
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").like("foo%"));
+        let (sql, params) = Postgres::build(select);
 
-        let (sql, params) = Postgres::build(query);
+        assert_eq!(
+            r#"SELECT DATE_TRUNC('week', "created_at") AS "week", EXTRACT(YEAR FROM "created_at"), ("created_at" AT TIME ZONE $1), ("created_at" + INTERVAL '3' MONTH) FROM "posts" WHERE "created_at" > (CURRENT_TIMESTAMP - INTERVAL '7' DAY)"#,
+            sql
+        );
+        assert_eq!(vec![Value::from("Europe/Helsinki")], params);
+    }
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+    #[test]
+    fn test_large_intervals_do_not_overflow() {
+        let mut select = Select::from_table("posts");
+        select.value(Expression::from(now()) + interval(i64::MAX, DateTimeField::Quarter));
+        select.value(Expression::from(now()) - interval(i64::MIN, DateTimeField::Week));
+
+        let (sql, _) = Postgres::build(select);
+
+        assert_eq!(
+            r#"SELECT (CURRENT_TIMESTAMP + INTERVAL '27670116110564327421' MONTH), (CURRENT_TIMESTAMP - INTERVAL '-64563604257983430656' DAY) FROM "posts""#,
+            sql
+        );
     }
 
     #[test]
-    fn test_not_ends_with_cast_to_string() {
-        let expected = expected_values(
-            r#"SELECT "test".* FROM "test" WHERE "jsonField"::text NOT LIKE $1"#,
-            vec!["foo%"],
+    fn test_numeric_functions() {
+        let mut select = Select::from_table("products");
+        select.value(round(Column::from("price"), Some(Expression::from(2))));
+        select.value(ceil(Column::from("price")));
+        select.value(power(Column::from("width"), 2));
+        select.value(modulo(Column::from("id"), 10));
+        select.value(nullif(Column::from("discount"), 0));
+        select.order_by(abs(Column::from("balance")).descend());
+        select.order_by(greatest(vec![
+            Column::from("created_at"),
+            Column::from("updated_at"),
+        ]));
+
+        let (sql, params, types) = Postgres::build_with_types(select);
+
+        assert_eq!(
+            r#"SELECT ROUND(CAST("price" AS numeric), $1), CEIL("price"), POWER("width", $2), MOD("id", $3), NULLIF("discount", $4) FROM "products" ORDER BY ABS("balance") DESC, GREATEST("created_at", "updated_at")"#,
+            sql
         );
+        assert_eq!(4, params.len());
+        assert_eq!(Some(SqlType::Integer), types[0]);
+    }
 
-        let mut query = Select::from_table("test");
-        query.so_that(Column::from("jsonField").not_like("foo%"));
+    #[test]
+    fn test_numeric_functions_in_update() {
+        let mut update = Update::table("accounts");
+        update.set(
+            "balance",
+            least(vec![
+                Expression::from(Column::from("balance")),
+                Expression::from(100),
+            ]),
+        );
+        update.set("score", floor(sqrt(Column::from("score"))));
 
-        let (sql, params) = Postgres::build(query);
+        let (sql, params) = Postgres::build(update);
 
-        assert_eq!(expected.0, sql);
-        assert_eq!(expected.1, params);
+        assert_eq!(
+            r#"UPDATE "accounts" SET "balance" = LEAST("balance", $1), "score" = FLOOR(SQRT("score"))"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(100)], params);
     }
 
     #[test]
-    fn test_default_insert() {
-        let mut insert = Insert::single_into("foo");
-        insert.value("foo", "bar");
-        insert.value("baz", default_value());
+    fn test_bitwise_and_unary_operators() {
+        let flags = || Expression::from(Column::from("flags"));
 
-        let (sql, _) = Postgres::build(insert);
+        let mut select = Select::from_table("users");
+        select.value(flags() ^ Expression::from(1));
+        select.value(flags() << Expression::from(2));
+        select.value(-Expression::from(Column::from("balance")));
+        select.value(!Expression::from(Column::from("active")));
+        select.so_that((flags() & Expression::from(4)).not_equals(0));
+        select.and_where((flags() | Expression::from(8)).equals(Column::from("mask")));
+
+        let (sql, params) = Postgres::build(select);
 
         assert_eq!(
-            "INSERT INTO \"foo\" (\"foo\",\"baz\") VALUES ($1,DEFAULT)",
+            r#"SELECT ("flags" # $1), ("flags" << $2), -("balance"), (NOT "active") FROM "users" WHERE (("flags" & $3) <> $4 AND ("flags" | $5) = "mask")"#,
             sql
         );
+        assert_eq!(5, params.len());
     }
 
     #[test]
-    fn join_is_inserted_positionally() {
-        let joined_table = Table::from("User").left_join(
-            Table::from("Post")
-                .alias("p")
-                .on(("p", "userId").equals(Column::from(("User", "id")))),
+    fn test_negated_negative_value_is_not_a_comment() {
+        let mut query = Select::from_table("t");
+        query.value(-Expression::from(-5));
+
+        let sql = Postgres::debug_sql(query);
+
+        assert_eq!(r#"SELECT -(-5) FROM "t""#, sql);
+    }
+
+    #[test]
+    fn test_multi_row_insert_split_rejects_wide_rows() {
+        let mut insert = Insert::multi_into("users", ["a", "b", "c"]);
+        insert.values((1, 2, 3));
+
+        let error = insert.split(2).unwrap_err();
+
+        assert_eq!(
+            "A row has 3 values, more than the maximum of 2 parameters.",
+            error.to_string()
         );
+    }
 
-        let mut q = Select::from_table(joined_table);
-        q.and_from("Toto");
+    #[test]
+    fn test_sql_value_into_json() {
+        let value = SqlValue::Array(vec![
+            SqlValue::Int32(1),
+            SqlValue::Numeric("12.50".to_string()),
+            SqlValue::Bytes(vec![1, 255]),
+            SqlValue::Uuid(1),
+            SqlValue::Json(serde_json::json!({"a": [1]})),
+            SqlValue::Null.with_type(SqlType::Text),
+        ]);
 
-        let (sql, _) = Postgres::build(q);
+        assert_eq!(
+            serde_json::json!([
+                1,
+                12.5,
+                "\\x01ff",
+                "00000000-0000-0000-0000-000000000001",
+                {"a": [1]},
+                null
+            ]),
+            value.into_json()
+        );
+    }
 
-        assert_eq!("SELECT \"User\".*, \"Toto\".* FROM \"User\" LEFT JOIN \"Post\" AS \"p\" ON \"p\".\"userId\" = \"User\".\"id\", \"Toto\"", sql);
+    #[test]
+    fn test_sql_value_equals_json_numbers() {
+        assert_eq!(SqlValue::Int16(5), serde_json::json!(5));
+        assert_eq!(SqlValue::Int32(5), serde_json::json!(5));
+        assert_eq!(SqlValue::Int64(5), serde_json::json!(5));
+        assert_eq!(SqlValue::Float(0.5), serde_json::json!(0.5));
+        assert_eq!(SqlValue::Double(0.5), serde_json::json!(0.5));
+        assert_ne!(SqlValue::Int32(5), serde_json::json!(5.0));
+        assert_ne!(SqlValue::Float(0.1), serde_json::json!(0.1));
     }
 
     #[test]
-    fn test_json_build_object_raw_value() {
-        let mut select = Select::default();
-        select.value(json_build_object([("id", raw("1"))]));
+    fn test_type_hints_convert_values() {
+        let mut select = Select::from_table("users");
+        select.value(left(Column::from("name"), 3));
+        select.value(left(Column::from("name"), i64::MAX));
+        select.so_that(
+            Expression::from(json_extract(
+                Column::from("data"),
+                JsonPath::array(["name"]),
+                false,
+            ))
+            .equals("Musti"),
+        );
 
-        let (sql, _) = Postgres::build(select);
+        let (_, params, types) = Postgres::build_with_types(select);
 
-        assert_eq!(r#"SELECT json_build_object('id', 1)"#, sql);
+        assert_eq!(
+            vec![
+                SqlValue::Int32(3),
+                SqlValue::Int64(i64::MAX),
+                SqlValue::Text("name".to_string()),
+                SqlValue::Json(Value::from("Musti")),
+            ],
+            params
+        );
+        assert_eq!(
+            vec![
+                Some(SqlType::Integer),
+                None,
+                Some(SqlType::Text),
+                Some(SqlType::Jsonb)
+            ],
+            types
+        );
     }
 
     #[test]
-    fn test_json_build_object_column() {
-        let mut select = Select::from_table("User");
-        select.value(json_build_object([("name", Column::from("name"))]));
+    fn test_deduplicate_json_documents() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".array_contains(serde_json::json!({"x": 1, "y": [1.5]}))
+                .and("b".array_contains(serde_json::json!({"x": 1, "y": [1.5]})))
+                .and("c".array_contains(serde_json::json!({"x": 2, "y": [1.5]}))),
+        );
 
-        let (sql, _) = Postgres::build(select);
+        let (sql, params) = Postgres::new().deduplicate_parameters().render(select);
 
         assert_eq!(
-            r#"SELECT json_build_object('name', "name") FROM "User""#,
+            r#"SELECT "users".* FROM "users" WHERE ("a" @> $1 AND "b" @> $1 AND "c" @> $2)"#,
             sql
         );
+        assert_eq!(2, params.len());
     }
 
     #[test]
-    fn test_cte() {
-        let mut insert = Insert::single_into("User");
-        insert.value("name", "Musti");
+    fn test_render_to_writer_streams_and_reports_errors() {
+        struct Limited(Vec<u8>);
 
-        let mut insert = insert.build();
-        insert.returning(["id", "name"]);
+        impl std::io::Write for Limited {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if self.0.len() + buf.len() > 16 {
+                    return Err(std::io::Error::new(std::io::ErrorKind::WriteZero, "full"));
+                }
 
-        let mut select = Select::from_table("public_user");
-        select.with(CommonTableExpression::new("public_user", insert));
-        select.columns(["id", "name"]);
+                self.0.extend_from_slice(buf);
+                Ok(buf.len())
+            }
 
-        let (sql, _) = Postgres::build(select);
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
 
-        assert_eq!(
-            r#"WITH "public_user" AS (INSERT INTO "User" ("name") VALUES ($1) RETURNING "id", "name") SELECT "id", "name" FROM "public_user""#,
-            sql
-        );
+        let mut select = Select::from_table("users");
+        select.so_that("id".equals(1));
+
+        let mut writer = Limited(Vec::new());
+        let error = Postgres::new()
+            .render_to_writer(select, &mut writer)
+            .unwrap_err();
+
+        assert_eq!("full", error.to_string());
+        assert_eq!(br#"SELECT "users".*"#, writer.0.as_slice());
     }
 }
@@ -0,0 +1,806 @@
+use crate::{ast::*, renderer::Renderer};
+use std::fmt::{self, Write};
+
+/// A visitor to generate queries for the SQLite database.
+///
+/// Parameters are substituted with `?`, and the returned values should be
+/// bound in the same order. JSON functions use the `->` and `->>` operators
+/// of SQLite 3.38.
+#[cfg_attr(feature = "docs", doc(cfg(feature = "sqlite")))]
+pub struct Sqlite {
+    query: String,
+    parameters: Vec<SqlValue>,
+}
+
+impl Sqlite {
+    /// A `datetime` modifier adding the quantity of the field, with weeks
+    /// written as days and quarters as months.
+    fn write_interval_modifier(&mut self, quantity: i128, field: DateTimeField) {
+        let (quantity, unit) = match field {
+            DateTimeField::Second => (quantity, "seconds"),
+            DateTimeField::Minute => (quantity, "minutes"),
+            DateTimeField::Hour => (quantity, "hours"),
+            DateTimeField::Day => (quantity, "days"),
+            DateTimeField::Week => (quantity * 7, "days"),
+            DateTimeField::Month => (quantity, "months"),
+            DateTimeField::Quarter => (quantity * 3, "months"),
+            DateTimeField::Year => (quantity, "years"),
+        };
+
+        self.write(format!("'{quantity:+} {unit}'"));
+    }
+}
+
+impl<'a> Renderer<'a> for Sqlite {
+    const C_BACKTICK_OPEN: &'static str = "\"";
+    const C_BACKTICK_CLOSE: &'static str = "\"";
+    const C_WILDCARD: &'static str = "%";
+    const MAX_PARAMETERS: usize = 32766;
+
+    fn build<Q>(query: Q) -> (String, Vec<SqlValue>)
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mut sqlite = Sqlite {
+            query: String::with_capacity(4096),
+            parameters: Vec::with_capacity(128),
+        };
+
+        Sqlite::visit_query(&mut sqlite, query.into());
+
+        (sqlite.query, sqlite.parameters)
+    }
+
+    fn write<D: fmt::Display>(&mut self, s: D) {
+        write!(&mut self.query, "{s}")
+            .expect("we ran out of memory or something else why write failed");
+    }
+
+    fn add_parameter(&mut self, value: SqlValue) {
+        self.parameters.push(value);
+    }
+
+    fn parameter_substitution(&mut self) {
+        self.write("?");
+    }
+
+    fn visit_limit_and_offset(&mut self, limit: Option<u32>, offset: Option<u32>) {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => {
+                self.write(" LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)));
+
+                self.write(" OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (None, Some(offset)) => {
+                // SQLite has no `OFFSET` without a `LIMIT`, a negative limit
+                // returns every row.
+                self.write(" LIMIT -1");
+
+                self.write(" OFFSET ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(offset)))
+            }
+            (Some(limit), None) => {
+                self.write(" LIMIT ");
+                self.visit_parameterized(SqlValue::Int64(i64::from(limit)))
+            }
+            (None, None) => (),
+        }
+    }
+
+    fn visit_ordering(&mut self, ordering: Ordering<'a>) {
+        let len = ordering.0.len();
+
+        for (i, (value, ordering)) in ordering.0.into_iter().enumerate() {
+            let direction = ordering.map(|dir| match dir {
+                Order::Asc => " ASC",
+                Order::Desc => " DESC",
+                Order::AscNullsFirst => " ASC NULLS FIRST",
+                Order::AscNullsLast => " ASC NULLS LAST",
+                Order::DescNullsFirst => " DESC NULLS FIRST",
+                Order::DescNullsLast => " DESC NULLS LAST",
+            });
+
+            self.visit_expression(value);
+            self.write(direction.unwrap_or(""));
+
+            if i < (len - 1) {
+                self.write(", ");
+            }
+        }
+    }
+
+    fn visit_insert(&mut self, insert: Insert<'a>) {
+        self.write("INSERT ");
+
+        if let Some(table) = insert.table.clone() {
+            self.write("INTO ");
+            self.visit_table(table, true);
+        }
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => {
+                if row.values.is_empty() {
+                    self.write(" DEFAULT VALUES");
+                } else {
+                    self.columns_to_bracket_list(insert.columns);
+                    self.write(" VALUES ");
+                    self.visit_row(row);
+                }
+            }
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                self.columns_to_bracket_list(insert.columns);
+                self.write(" VALUES ");
+                let values_len = values.len();
+
+                for (i, row) in values.into_iter().enumerate() {
+                    self.visit_row(row);
+
+                    if i < (values_len - 1) {
+                        self.write(", ");
+                    }
+                }
+            }
+            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr)),
+        }
+
+        match insert.on_conflict {
+            Some(OnConflict::DoNothing) => self.write(" ON CONFLICT DO NOTHING"),
+            Some(OnConflict::Update(update, constraints)) => {
+                self.write(" ON CONFLICT");
+                self.columns_to_bracket_list(constraints);
+                self.write(" DO ");
+
+                self.visit_upsert(update);
+            }
+            None => (),
+        }
+
+        if let Some(returning) = insert.returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write(" RETURNING ");
+                self.visit_columns(values);
+            }
+        };
+
+        self.visit_comment(insert.comment);
+    }
+
+    fn visit_delete(&mut self, delete: Delete<'a>) {
+        self.write("DELETE FROM ");
+        self.visit_table(delete.table, true);
+
+        if let Some(conditions) = delete.conditions {
+            self.write(" WHERE ");
+            self.visit_conditions(conditions);
+        }
+
+        if let Some(returning) = delete.returning {
+            self.write(" RETURNING ");
+            self.visit_columns(returning);
+        }
+
+        self.visit_comment(delete.comment);
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'a>) {
+        self.write("GROUP_CONCAT");
+        self.surround_with("(", ")", |s| s.visit_expression(value));
+    }
+
+    fn visit_similar_to(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+        panic!("SIMILAR TO is not supported on SQLite")
+    }
+
+    /// SQLite compares null-safely with `IS` and `IS NOT`.
+    fn visit_distinct_from(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) {
+        self.visit_expression(left);
+        self.write(if not { " IS " } else { " IS NOT " });
+        self.visit_expression(right);
+    }
+
+    fn visit_substring(&mut self, substring: Substring<'a>) {
+        self.write("substr");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*substring.expression);
+            s.write(", ");
+            s.visit_integer_argument(*substring.start);
+
+            if let Some(length) = substring.length {
+                s.write(", ");
+                s.visit_integer_argument(*length);
+            }
+        });
+    }
+
+    /// SQLite has no XOR operator, so it is computed as `(a | b) - (a & b)`.
+    fn visit_bitwise_xor(&mut self, left: Expression<'a>, right: Expression<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.surround_with("(", ")", |s| {
+                s.visit_expression(left.clone());
+                s.write(" | ");
+                s.visit_expression(right.clone());
+            });
+
+            s.write(" - ");
+
+            s.surround_with("(", ")", |s| {
+                s.visit_expression(left);
+                s.write(" & ");
+                s.visit_expression(right);
+            });
+        });
+    }
+
+    /// `trim`, `ltrim` or `rtrim`, which take the characters to remove as
+    /// their second argument.
+    fn visit_trim(&mut self, trim: Trim<'a>) {
+        self.write(match trim.side {
+            TrimSide::Leading => "ltrim",
+            TrimSide::Trailing => "rtrim",
+            TrimSide::Both => "trim",
+        });
+
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*trim.expression);
+
+            if let Some(characters) = trim.characters {
+                s.write(", ");
+                s.visit_expression(*characters);
+            }
+        });
+    }
+
+    /// `length` counts characters of text values in SQLite.
+    fn visit_length(&mut self, length: Length<'a>) {
+        self.write("length");
+        self.surround_with("(", ")", |s| s.visit_expression(*length.expression));
+    }
+
+    fn visit_position(&mut self, position: Position<'a>) {
+        self.write("instr");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*position.expression);
+            s.write(", ");
+            s.visit_expression(*position.substring);
+        });
+    }
+
+    fn visit_left(&mut self, left: Left<'a>) {
+        self.write("substr");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*left.expression);
+            s.write(", 1, ");
+            s.visit_integer_argument(*left.count);
+        });
+    }
+
+    /// `substr(string, -count, count)`, counting from the end of the string.
+    fn visit_right(&mut self, right: Right<'a>) {
+        let count = *right.count;
+
+        self.write("substr");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*right.expression);
+            s.write(", -");
+            s.visit_integer_argument(count.clone());
+            s.write(", ");
+            s.visit_integer_argument(count);
+        });
+    }
+
+    fn visit_pad(&mut self, _: Pad<'a>) {
+        panic!("LPAD and RPAD are not supported on SQLite")
+    }
+
+    fn visit_split_part(&mut self, _: SplitPart<'a>) {
+        panic!("SPLIT_PART is not supported on SQLite")
+    }
+
+    /// SQLite has no interval type. An interval is a `datetime` modifier
+    /// such as `'+7 days'`.
+    fn visit_interval(&mut self, interval: Interval) {
+        self.write_interval_modifier(i128::from(interval.quantity), interval.field);
+    }
+
+    /// `datetime(timestamp, '+7 days')`.
+    fn visit_interval_arithmetic(
+        &mut self,
+        left: Expression<'a>,
+        interval: Interval,
+        subtract: bool,
+    ) {
+        // Widened, so that negating the quantity does not overflow.
+        let quantity = i128::from(interval.quantity);
+
+        self.write("datetime");
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(left);
+            s.write(", ");
+            s.write_interval_modifier(if subtract { -quantity } else { quantity }, interval.field);
+        });
+    }
+
+    /// `DATE_TRUNC` with the `datetime` modifiers, or `strftime` zeroing the
+    /// fields below an hour. Weeks start on Monday.
+    fn visit_date_trunc(&mut self, date_trunc: DateTrunc<'a>) {
+        let expression = *date_trunc.expression;
+
+        let format = match date_trunc.field {
+            DateTimeField::Second => "%Y-%m-%d %H:%M:%S",
+            DateTimeField::Minute => "%Y-%m-%d %H:%M:00",
+            DateTimeField::Hour => "%Y-%m-%d %H:00:00",
+            field => {
+                self.write("datetime");
+                self.surround_with("(", ")", |s| {
+                    s.visit_expression(expression.clone());
+
+                    match field {
+                        DateTimeField::Day => s.write(", 'start of day'"),
+                        DateTimeField::Week => s.write(", 'start of day', '-6 days', 'weekday 1'"),
+                        DateTimeField::Month => s.write(", 'start of month'"),
+                        DateTimeField::Quarter => {
+                            s.write(", 'start of month', '-' || ((strftime('%m', ");
+                            s.visit_expression(expression);
+                            s.write(") - 1) % 3) || ' months'");
+                        }
+                        _ => s.write(", 'start of year'"),
+                    }
+                });
+
+                return;
+            }
+        };
+
+        self.write(format!("strftime('{format}', "));
+        self.visit_expression(expression);
+        self.write(")");
+    }
+
+    /// The field formatted with `strftime` and cast to an integer. The week
+    /// is the week of the year starting on the first Monday, `%W`.
+    fn visit_extract(&mut self, extract: Extract<'a>) {
+        let format = match extract.field {
+            DateTimeField::Second => "%S",
+            DateTimeField::Minute => "%M",
+            DateTimeField::Hour => "%H",
+            DateTimeField::Day => "%d",
+            DateTimeField::Week => "%W",
+            DateTimeField::Month | DateTimeField::Quarter => "%m",
+            DateTimeField::Year => "%Y",
+        };
+
+        let quarter = extract.field == DateTimeField::Quarter;
+
+        if quarter {
+            self.write("((");
+        }
+
+        self.write(format!("CAST(strftime('{format}', "));
+        self.visit_expression(*extract.expression);
+        self.write(") AS INTEGER)");
+
+        if quarter {
+            self.write(" + 2) / 3)");
+        }
+    }
+
+    fn visit_at_time_zone(&mut self, _: AtTimeZone<'a>) {
+        panic!("AT TIME ZONE is not supported on SQLite")
+    }
+
+    /// The scalar `MAX` and `MIN` functions. With a single argument they
+    /// would be aggregates, so a single value is written as is.
+    fn visit_greatest_least(&mut self, mut greatest_least: GreatestLeast<'a>) {
+        if greatest_least.exprs.len() == 1 {
+            let expression = greatest_least.exprs.pop().unwrap();
+            return self.surround_with("(", ")", |s| s.visit_expression(expression));
+        }
+
+        self.write(if greatest_least.greatest {
+            "MAX"
+        } else {
+            "MIN"
+        });
+
+        self.surround_with("(", ")", |s| s.visit_columns(greatest_least.exprs));
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_json_extract(&mut self, json_extract: JsonExtract<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*json_extract.column);
+            s.write(if json_extract.extract_as_string {
+                " ->> "
+            } else {
+                " -> "
+            });
+
+            match json_extract.path {
+                #[cfg(feature = "mysql")]
+                JsonPath::String(path) => s.visit_parameterized(SqlValue::Text(path.into_owned())),
+                #[cfg(feature = "postgresql")]
+                JsonPath::Array(_) => {
+                    panic!("JSON path array notation is not supported for SQLite")
+                }
+            }
+        });
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*extract.expr);
+            s.write(" -> '$[#-1]'");
+        });
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_json_extract_first_array_item(&mut self, extract: JsonExtractFirstArrayElem<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*extract.expr);
+            s.write(" -> '$[0]'");
+        });
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_array_contains(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+        panic!("JSON array containment is not supported on SQLite")
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_array_contained(&mut self, _: Expression<'a>, _: Expression<'a>, _: bool) {
+        panic!("JSON array containment is not supported on SQLite")
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_array_overlaps(&mut self, _: Expression<'a>, _: Expression<'a>) {
+        panic!("JSON array overlapping is not supported on SQLite")
+    }
+
+    /// Compares the `json_type`, which has separate types for integers and
+    /// reals, and for `true` and `false`.
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) {
+        self.write("json_type");
+        self.surround_with("(", ")", |s| s.visit_expression(left));
+
+        let types = match json_type {
+            JsonType::Array => "'array'",
+            JsonType::Object => "'object'",
+            JsonType::String => "'text'",
+            JsonType::Number => "'integer', 'real'",
+            JsonType::Boolean => "'true', 'false'",
+            JsonType::Null => "'null'",
+            JsonType::ColumnRef(column) => {
+                self.write(if not { " != " } else { " = " });
+                self.write("json_type");
+                self.surround_with("(", ")", |s| s.visit_column(*column));
+
+                return;
+            }
+        };
+
+        self.write(if not { " NOT IN " } else { " IN " });
+        self.surround_with("(", ")", |s| s.write(types));
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) {
+        self.surround_with("(", ")", |s| {
+            s.visit_expression(*json_unquote.expr);
+            s.write(" ->> '$'");
+        });
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_to_jsonb(&mut self, _: ToJsonb<'a>) {
+        panic!("to_jsonb is not supported on SQLite")
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_json_build_object(&mut self, json_build_object: JsonBuildObject<'a>) {
+        let len = json_build_object.values.len();
+
+        self.write("json_object");
+        self.surround_with("(", ")", |s| {
+            for (i, (name, value)) in json_build_object.values.into_iter().enumerate() {
+                s.visit_parameterized(SqlValue::Text(name.into_owned()));
+                s.write(", ");
+                s.visit_expression(value);
+
+                if i < (len - 1) {
+                    s.write(", ");
+                }
+            }
+        });
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_json_agg(&mut self, json_agg: JsonAgg<'a>) {
+        self.write("json_group_array(");
+
+        if json_agg.distinct {
+            self.write("DISTINCT ");
+        }
+
+        self.visit_expression(json_agg.expression);
+
+        if let Some(ordering) = json_agg.order_by {
+            self.write(" ORDER BY ");
+            self.visit_ordering(ordering);
+        }
+
+        self.write(")");
+    }
+
+    #[cfg(feature = "postgresql")]
+    fn visit_encode(&mut self, encode: Encode<'a>) {
+        match encode.format {
+            EncodeFormat::Hex => {
+                self.write("hex");
+                self.surround_with("(", ")", |s| s.visit_expression(encode.expression));
+            }
+            EncodeFormat::Base64 | EncodeFormat::Escape => {
+                panic!("Only the hex encoding is supported on SQLite")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::renderer::*;
+
+    #[test]
+    fn test_identifiers_and_parameters() {
+        let mut select = Select::from_table("users");
+        select.so_that("name".equals("Musti").and("age".greater_than(3)));
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("name" = ? AND "age" > ?)"#,
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti"), Value::from(3)], params);
+    }
+
+    #[test]
+    fn test_offset_without_limit() {
+        let mut select = Select::from_table("users");
+        select.offset(10);
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(r#"SELECT "users".* FROM "users" LIMIT -1 OFFSET ?"#, sql);
+        assert_eq!(vec![Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_insert_on_conflict_do_nothing() {
+        let mut insert = Insert::single_into("users");
+        insert.value("id", 1);
+
+        let mut insert = insert.build();
+        insert.on_conflict(OnConflict::DoNothing);
+        insert.returning(vec!["id"]);
+
+        let (sql, _) = Sqlite::build(insert);
+
+        assert_eq!(
+            r#"INSERT INTO "users" ("id") VALUES (?) ON CONFLICT DO NOTHING RETURNING "id""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_and_regex_matching() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            Column::from("name")
+                .ilike("%musti%")
+                .and(Column::from("email").not_regex_match("^b"))
+                .and(Column::from("email").iregex_match("^c")),
+        );
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE (LOWER("name") LIKE LOWER(?) AND "email" NOT REGEXP ? AND LOWER("email") REGEXP LOWER(?))"#,
+            sql
+        );
+        assert_eq!(3, params.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "SIMILAR TO is not supported on SQLite")]
+    fn test_similar_to_is_not_supported() {
+        let mut select = Select::from_table("users");
+        select.so_that(Column::from("code").similar_to("(a|b)%"));
+
+        Sqlite::build(select);
+    }
+
+    #[test]
+    fn test_quantified_comparison_uses_exists() {
+        let mut scores = Select::from_table("scores");
+        scores.value(max("score"));
+        scores.group_by("team");
+
+        let mut select = Select::from_table("users");
+        select.so_that(("users", "score").quantified(
+            ComparisonOperator::Equals,
+            Quantifier::Any,
+            scores,
+        ));
+
+        let (sql, _) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE EXISTS (SELECT "quantified".* FROM (SELECT MAX("score") AS "value" FROM "scores" GROUP BY "team") AS "quantified" WHERE "users"."score" = "quantified"."value")"#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_distinct_from() {
+        let mut select = Select::from_table("users");
+        select.so_that(
+            "a".is_distinct_from(1)
+                .and("b".is_not_distinct_from(Column::from("c"))),
+        );
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT "users".* FROM "users" WHERE ("a" IS NOT ? AND "b" IS "c")"#,
+            sql
+        );
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_string_functions() {
+        let mut select = Select::from_table("users");
+        select.value(substring(
+            Column::from("name"),
+            2,
+            Some(Expression::from(3)),
+        ));
+        select.value(trim_characters(
+            Column::from("code"),
+            TrimSide::Leading,
+            Some(Expression::from("0")),
+        ));
+        select.value(trim(Column::from("name")));
+        select.value(length(Column::from("name")));
+        select.value(position("@", Column::from("email")));
+        select.value(left(Column::from("name"), 1));
+        select.value(right(Column::from("name"), 2));
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT substr("name", ?, ?), ltrim("code", ?), trim("name"), length("name"), instr("email", ?), substr("name", 1, ?), substr("name", -?, ?) FROM "users""#,
+            sql
+        );
+        assert_eq!(
+            vec![
+                Value::from(2),
+                Value::from(3),
+                Value::from("0"),
+                Value::from("@"),
+                Value::from(1),
+                Value::from(2),
+                Value::from(2),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "SPLIT_PART is not supported on SQLite")]
+    fn test_split_part_is_not_supported() {
+        let mut select = Select::from_table("users");
+        select.value(split_part(Column::from("email"), "@", 2));
+
+        Sqlite::build(select);
+    }
+
+    #[test]
+    fn test_date_time_functions() {
+        let mut select = Select::from_table("posts");
+        select.value(date_trunc(DateTimeField::Hour, Column::from("created_at")));
+        select.value(date_trunc(DateTimeField::Week, Column::from("created_at")));
+        select.value(date_trunc(
+            DateTimeField::Quarter,
+            Column::from("created_at"),
+        ));
+        select.value(extract(DateTimeField::Quarter, Column::from("created_at")));
+        select
+            .value(Expression::from(Column::from("created_at")) + interval(1, DateTimeField::Week));
+        select.so_that(
+            "created_at".greater_than(Expression::from(now()) - interval(7, DateTimeField::Day)),
+        );
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT strftime('%Y-%m-%d %H:00:00', "created_at"), datetime("created_at", 'start of day', '-6 days', 'weekday 1'), datetime("created_at", 'start of month', '-' || ((strftime('%m', "created_at") - 1) % 3) || ' months'), ((CAST(strftime('%m', "created_at") AS INTEGER) + 2) / 3), datetime("created_at", '+7 days') FROM "posts" WHERE "created_at" > datetime(CURRENT_TIMESTAMP, '-7 days')"#,
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_large_intervals_do_not_overflow() {
+        let mut select = Select::from_table("posts");
+        select.value(Expression::from(now()) - interval(i64::MIN, DateTimeField::Quarter));
+
+        let (sql, _) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT datetime(CURRENT_TIMESTAMP, '+27670116110564327424 months') FROM "posts""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_bitwise_xor() {
+        let mut select = Select::from_table("users");
+        select.value(Expression::from(Column::from("flags")) ^ Expression::from(1));
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT (("flags" | ?) - ("flags" & ?)) FROM "users""#,
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_greatest_and_least() {
+        let mut select = Select::from_table("accounts");
+        select.value(greatest(vec![
+            Column::from("balance"),
+            Column::from("limit"),
+        ]));
+        select.value(least(vec![Column::from("balance")]));
+
+        let (sql, _) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT MAX("balance", "limit"), ("balance") FROM "accounts""#,
+            sql
+        );
+    }
+
+    #[test]
+    fn test_json_extract_and_type() {
+        let mut select = Select::from_table("users");
+        select.value(json_extract(
+            Column::from("data"),
+            JsonPath::string("$.name"),
+            true,
+        ));
+        select.so_that(Column::from("data").json_type_equals(JsonType::Number));
+
+        let (sql, params) = Sqlite::build(select);
+
+        assert_eq!(
+            r#"SELECT ("data" ->> ?) FROM "users" WHERE json_type("data") IN ('integer', 'real')"#,
+            sql
+        );
+        assert_eq!(vec![Value::from("$.name")], params);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::ast::{SqlType, SqlValue};
+use anyhow::anyhow;
+use std::collections::{HashMap, HashSet};
+
+/// A rendered query with named placeholders, to be executed repeatedly with
+/// different bindings without rebuilding the AST.
+///
+/// The parameters hold the values known when the query was built. Every
+/// placeholder has a `NULL` slot in the parameters, and the placeholders map
+/// the name of the placeholder to the index of its slot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    /// The SQL string of the query.
+    pub sql: String,
+    /// The parameters of the query, with `NULL` in the placeholder slots.
+    pub parameters: Vec<SqlValue>,
+    /// The SQL types of the parameters, including the types the query
+    /// expects in the placeholder slots.
+    pub parameter_types: Vec<Option<SqlType>>,
+    /// The index of the parameter slot of every placeholder, by name.
+    pub placeholders: HashMap<String, usize>,
+}
+
+impl Template {
+    /// Returns the parameters of the query with every placeholder replaced by
+    /// its bound value. Fails if a placeholder is left without a value, or a
+    /// value is bound to a placeholder the query does not have.
+    ///
+    /// ```rust
+    /// # use grafbase_sql_ast::{ast::*, renderer::Postgres};
+    /// # fn main() -> anyhow::Result<()> {
+    /// let mut query = Select::from_table("users");
+    /// query.so_that("tenant_id".equals(placeholder("tenant")).and("active".equals(true)));
+    ///
+    /// let template = Postgres::new().render_template(query);
+    ///
+    /// assert_eq!(
+    ///     r#"SELECT "users".* FROM "users" WHERE ("tenant_id" = $1 AND "active" = $2)"#,
+    ///     template.sql
+    /// );
+    ///
+    /// let params = template.bind([("tenant", SqlValue::Int64(1))])?;
+    /// assert_eq!(vec![SqlValue::Int64(1), SqlValue::Boolean(true)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bind<'a, I>(&self, bindings: I) -> anyhow::Result<Vec<SqlValue>>
+    where
+        I: IntoIterator<Item = (&'a str, SqlValue)>,
+    {
+        let mut parameters = self.parameters.clone();
+        let mut bound = HashSet::new();
+
+        for (name, value) in bindings {
+            let index = self
+                .placeholders
+                .get(name)
+                .ok_or_else(|| anyhow!("The query has no placeholder named `{name}`."))?;
+
+            parameters[*index] = value;
+            bound.insert(name);
+        }
+
+        let mut missing: Vec<_> = self
+            .placeholders
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !bound.contains(name))
+            .collect();
+
+        if !missing.is_empty() {
+            missing.sort_unstable();
+
+            return Err(anyhow!(
+                "No value bound to the placeholders: {}.",
+                missing.join(", ")
+            ));
+        }
+
+        Ok(parameters)
+    }
+}